@@ -1,8 +1,10 @@
 use std::path::PathBuf;
+use std::time::Duration;
 
 use clap::{Args, Parser, Subcommand, ValueEnum};
+use netdev::ipnet::IpNet;
 
-use crate::cmd::monitor::{SortKey, Unit};
+use crate::cmd::monitor::{Metric, SortKey, Unit};
 
 /// nifa - Cross-platform CLI tool for network information
 #[derive(Debug, Parser)]
@@ -16,15 +18,98 @@ pub struct Cli {
     #[arg(short='f', long, value_enum, default_value_t = OutputFormat::Tree)]
     pub format: OutputFormat,
 
+    /// Render the snapshot through a minijinja template file instead of the built-in formats
+    /// (list, show, os and export)
+    #[arg(long, global = true, value_name = "PATH")]
+    pub template: Option<PathBuf>,
+
+    /// Append a quick internet connectivity verdict (TCP connect to well-known hosts)
+    #[arg(long)]
+    pub probe: bool,
+
     /// With vendor info (OUI lookup)
     #[arg(long, default_value_t = false)]
     pub with_vendor: bool,
 
+    /// Use ASCII connectors in tree output
+    #[arg(long, global = true)]
+    pub ascii: bool,
+
+    /// Sections to omit from tree output (comma-separated)
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        value_delimiter = ',',
+        value_name = "SECTIONS"
+    )]
+    pub hide: Vec<Section>,
+
+    /// Sort addresses and DNS servers in JSON/YAML too (tree output is always sorted)
+    #[arg(long, global = true)]
+    pub sort_addresses: bool,
+
+    /// Prefix interface names with a type glyph in tree output (ASCII tags with --ascii)
+    #[arg(long, global = true)]
+    pub icons: bool,
+
+    /// Disable colored output (also honors the NO_COLOR env var)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// Report how long collection and rendering took on stderr (list, show, os)
+    #[arg(long, global = true)]
+    pub timing: bool,
+
+    /// Print the raw netdev interface data (Debug format) to stderr before running
+    #[arg(long, global = true)]
+    pub debug_dump: bool,
+
     /// Subcommand
     #[command(subcommand)]
     pub command: Option<Command>,
 }
 
+impl Cli {
+    /// Whether colored output is enabled
+    pub fn use_color(&self) -> bool {
+        !self.no_color && std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+    }
+}
+
+/// Tree output sections that can be hidden with `--hide`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Section {
+    Mac,
+    Mtu,
+    Speed,
+    Flags,
+    Ipv4,
+    Ipv6,
+    Dns,
+    Gateway,
+    Dhcp,
+    Stats,
+}
+
+/// Columns available to `export --table-json`
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum TableField {
+    Name,
+    Index,
+    Type,
+    State,
+    Mac,
+    Mtu,
+    Ipv4,
+    Ipv6,
+    Gateway,
+    Dns,
+    Default,
+    RxBytes,
+    TxBytes,
+}
+
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub enum OutputFormat {
     Tree,
@@ -40,20 +125,50 @@ pub enum Command {
     Show(ShowArgs),
     /// Monitor traffic statistics for all interfaces
     Monitor(MonitorArgs),
+    /// Short alias for `monitor` (TUI, 1s interval, sorted by total, all interfaces)
+    Top(MonitorArgs),
     /// Show OS/network stack/permission information
-    Os,
+    Os(OsArgs),
     /// Export snapshot as JSON/YAML
     Export(ExportArgs),
+    /// Run network diagnostics
+    Check(CheckArgs),
+    /// Show the ARP/NDP neighbor cache
+    Arp(ArpArgs),
+    /// Show the effective configuration
+    Config,
+    /// Validate interfaces against an expected configuration file
+    Validate(ValidateArgs),
     /// Show public IP information
     Public(PublicArgs),
+    /// Show the VPN heuristic with its scoring breakdown
+    Vpn(VpnArgs),
+    /// Wait until the default interface is up with a global address
+    WaitOnline(WaitArgs),
+    /// Bring the specified interface up (requires privileges)
+    Up(AdminArgs),
+    /// Bring the specified interface down (requires privileges)
+    Down(AdminArgs),
 }
 
 /// List command arguments
 #[derive(Args, Debug)]
 pub struct ListArgs {
     /// Filter by name (supports partial match)
-    #[arg(long)]
+    #[arg(long, conflicts_with = "name_regex")]
     pub name_like: Option<String>,
+    /// Filter by name using a regular expression
+    #[arg(long, value_name = "PATTERN")]
+    pub name_regex: Option<String>,
+    /// Hide interfaces whose name contains STR (repeatable)
+    #[arg(long, value_name = "STR")]
+    pub exclude_name: Vec<String>,
+    /// Only show interfaces listed in PATH (one name or glob per line)
+    #[arg(long, value_name = "PATH")]
+    pub include_file: Option<PathBuf>,
+    /// Hide interfaces listed in PATH (one name or glob per line)
+    #[arg(long, value_name = "PATH")]
+    pub exclude_file: Option<PathBuf>,
     /// Show UP status interfaces only
     #[arg(long, conflicts_with = "down")]
     pub up: bool,
@@ -72,30 +187,240 @@ pub struct ListArgs {
     /// Show interfaces with IPv6 address only
     #[arg(long)]
     pub ipv6: bool,
+    /// Show interfaces with an address inside CIDR (IPv4 or IPv6)
+    #[arg(long, value_name = "CIDR")]
+    pub in_network: Option<IpNet>,
+    /// Show interfaces with a gateway only
+    #[arg(long, conflicts_with = "no_gateway")]
+    pub has_gateway: bool,
+    /// Show interfaces without a gateway only
+    #[arg(long)]
+    pub no_gateway: bool,
+    /// Nest bridge/bond members under their master (tree output)
+    #[arg(long)]
+    pub topology: bool,
+    /// Show only interfaces that are new, removed or changed (ignoring counters) vs an exported snapshot
+    #[arg(long, value_name = "SNAPSHOT", conflicts_with = "topology")]
+    pub changed_since: Option<PathBuf>,
+    /// Print only the number of matching interfaces
+    #[arg(long)]
+    pub count: bool,
+    /// Exit with status 1 when no interface matches the filters
+    #[arg(long)]
+    pub fail_on_empty: bool,
+    /// Print RX/TX byte totals per interface type instead of the interfaces
+    #[arg(long, conflicts_with_all = ["topology", "changed_since", "count"])]
+    pub sum_by_type: bool,
+    /// Display unit for --sum-by-type totals
+    #[arg(long, value_enum, default_value_t=Unit::Bytes)]
+    pub unit: Unit,
 }
 
 /// Show command arguments
 #[derive(Args, Debug)]
 pub struct ShowArgs {
-    /// Show details for specified interface
+    /// Show details for specified interface (`-` reads names from stdin, one per line)
+    pub iface: String,
+    /// Sample stats twice this many ms apart to show RX/s and TX/s (0 = skip)
+    #[arg(long, value_name = "MS", default_value_t = 500)]
+    pub sample_ms: u64,
+}
+
+/// Vpn command arguments
+#[derive(Args, Debug)]
+pub struct VpnArgs {
+    /// Target interface (default: the default interface)
+    #[arg(short, long)]
+    pub iface: Option<String>,
+}
+
+/// Up/Down command arguments
+#[derive(Args, Debug)]
+pub struct AdminArgs {
+    /// Target interface name
     pub iface: String,
 }
 
+/// Aggregate-total options shared by `monitor` and `os`
+#[derive(Args, Debug, Clone, Copy)]
+pub struct TotalsArgs {
+    /// Count loopback in aggregate totals (default: loopback is excluded)
+    #[arg(long)]
+    pub include_loopback: bool,
+}
+
 /// Monitor command arguments
 #[derive(Args, Debug)]
 pub struct MonitorArgs {
     /// Target interface (default: all)
     #[arg(short, long)]
     pub iface: Option<String>,
+    /// Filter interfaces by name using a regular expression
+    #[arg(long, value_name = "PATTERN")]
+    pub name_regex: Option<String>,
+    /// Hide interfaces whose name contains STR (repeatable)
+    #[arg(long, value_name = "STR")]
+    pub exclude_name: Vec<String>,
+    /// Only show interfaces listed in PATH (one name or glob per line)
+    #[arg(long, value_name = "PATH")]
+    pub include_file: Option<PathBuf>,
+    /// Hide interfaces listed in PATH (one name or glob per line)
+    #[arg(long, value_name = "PATH")]
+    pub exclude_file: Option<PathBuf>,
     /// Sort key
     #[arg(short='s', long, value_enum, default_value_t=SortKey::Total)]
     pub sort: SortKey,
-    /// Monitor interval in seconds
-    #[arg(short = 'd', long, default_value = "1")]
-    pub interval: u64,
+    /// Monitor interval (e.g. 500ms, 2m; a bare number is seconds)
+    #[arg(short = 'd', long, value_name = "DURATION", default_value = "1", value_parser = parse_duration)]
+    pub interval: Duration,
     /// Display unit (bytes or bits)
     #[arg(long, value_enum, default_value_t=Unit::Bytes)]
     pub unit: Unit,
+    /// Average RX/s and TX/s over the last N seconds (1 = per-tick rate, totals stay exact)
+    #[arg(long, value_name = "SECONDS", default_value_t = 1)]
+    pub avg_window: u64,
+    /// Show only the N busiest rows by the active sort key (0 = all)
+    #[arg(long, value_name = "N", default_value_t = 0)]
+    pub top: usize,
+    /// Count RX/s, TX/s and totals in bytes or packets (sorting follows)
+    #[arg(long, value_enum, default_value_t = Metric::Bytes)]
+    pub metric: Metric,
+    /// Add ΔRX/ΔTX columns with the bytes (or packets) moved during the last tick
+    #[arg(long)]
+    pub show_delta: bool,
+    /// Add RX avg/TX avg columns with the average frame size (bytes/packet) of the last tick
+    #[arg(long)]
+    pub show_avg_frame: bool,
+    /// Exit after N seconds (0 = run until quit)
+    #[arg(long, value_name = "SECONDS", default_value_t = 0)]
+    pub duration: u64,
+    /// Auto-scale values: totals in bytes, rates fall back to packets/s when
+    /// byte counters are flat but packets move (takes precedence over --unit)
+    #[arg(long)]
+    pub auto_unit: bool,
+    /// Write the rate history (last hour) to a PNG line chart on exit
+    #[arg(long, value_name = "PATH.png")]
+    pub plot: Option<PathBuf>,
+    #[command(flatten)]
+    pub totals: TotalsArgs,
+    /// Monitor a remote host: run `nifa -f json export` there over SSH every tick
+    #[arg(long, value_name = "TARGET")]
+    pub ssh: Option<String>,
+    /// Replay `name rx_bytes tx_bytes` blocks from a file or named pipe, one per tick (testing)
+    #[arg(long, value_name = "PATH", hide = true, conflicts_with = "ssh")]
+    pub stats_source: Option<PathBuf>,
+    /// Table columns: all (wide), IFACE/RX/s/TX/s only (narrow), or by terminal width (auto)
+    #[arg(long, value_enum, default_value_t = MonitorLayout::Auto)]
+    pub layout: MonitorLayout,
+    /// Interactive table, or one JSON record / InfluxDB line per interface and tick on stdout
+    #[arg(long, value_enum, default_value_t = MonitorOutput::Tui)]
+    pub output_mode: MonitorOutput,
+    /// ndjson: rx+tx below this many bits/s is reported as level "idle"
+    #[arg(long, value_name = "BPS", default_value_t = 8_000)]
+    pub idle_threshold: u64,
+    /// ndjson: rx+tx at or above this many bits/s is reported as level "high"
+    /// (also the trigger for --bell and --notify)
+    #[arg(long, value_name = "BPS", default_value_t = 100_000_000)]
+    pub high_threshold: u64,
+    /// Ring the terminal bell when an interface crosses --high-threshold
+    #[arg(long)]
+    pub bell: bool,
+    /// Send a desktop notification when an interface crosses --high-threshold
+    /// (notify-send on Linux/BSD, osascript on macOS)
+    #[arg(long)]
+    pub notify: bool,
+}
+
+/// Output mode of the monitor command
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum MonitorOutput {
+    #[default]
+    Tui,
+    Ndjson,
+    /// InfluxDB line protocol
+    Influx,
+}
+
+/// Column layout of the monitor table
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum MonitorLayout {
+    Wide,
+    Narrow,
+    #[default]
+    Auto,
+}
+
+/// Validate command arguments
+#[derive(Args, Debug)]
+pub struct ValidateArgs {
+    /// Expectations file (YAML or JSON)
+    pub file: PathBuf,
+    /// Validate a snapshot saved by `export` instead of the live interfaces
+    #[arg(long, value_name = "FILE")]
+    pub snapshot: Option<PathBuf>,
+}
+
+/// Wait-online command arguments
+#[derive(Args, Debug)]
+pub struct WaitArgs {
+    /// Give up after this long (e.g. 90s, 5m; a bare number is seconds)
+    #[arg(long, value_name = "DURATION", default_value = "30", value_parser = parse_duration)]
+    pub timeout: Duration,
+    /// Poll interval (e.g. 500ms; a bare number is seconds)
+    #[arg(long, value_name = "DURATION", default_value = "1", value_parser = parse_duration)]
+    pub interval: Duration,
+    /// Do not print progress to stderr
+    #[arg(short, long)]
+    pub quiet: bool,
+}
+
+/// Sort order of neighbor entries
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum ArpSort {
+    #[default]
+    Ip,
+    Iface,
+}
+
+/// Check command arguments
+#[derive(Args, Debug)]
+pub struct CheckArgs {
+    /// Sample error/drop/collision counters twice, SECONDS apart, and warn on increases
+    #[arg(long, value_name = "SECONDS", value_parser = clap::value_parser!(u64).range(1..))]
+    pub sample_errors: Option<u64>,
+    /// Warn about links whose RX and TX speeds differ (e.g. DSL/cable)
+    #[arg(long)]
+    pub asymmetric_speed: bool,
+}
+
+/// Arp command arguments
+#[derive(Args, Debug)]
+pub struct ArpArgs {
+    /// Show entries learned on the specified interface only
+    #[arg(short, long)]
+    pub iface: Option<String>,
+    /// Sort key
+    #[arg(short, long, value_enum, default_value_t = ArpSort::Ip)]
+    pub sort: ArpSort,
+}
+
+/// OS command arguments
+#[derive(Args, Debug)]
+pub struct OsArgs {
+    /// Display unit for traffic totals
+    #[arg(long, value_enum, default_value_t=Unit::Bytes)]
+    pub unit: Unit,
+    /// Probe well-known DoH endpoints for reachability (3s timeout each)
+    #[arg(long)]
+    pub check_doh: bool,
+    #[command(flatten)]
+    pub totals: TotalsArgs,
+    /// Also show every raw os_info field (an `os_info` key in JSON/YAML)
+    #[arg(long)]
+    pub verbose_os: bool,
+    /// Include open TCP/UDP socket and conntrack entry counts
+    #[arg(long)]
+    pub sockets: bool,
 }
 
 /// Export command arguments
@@ -104,14 +429,93 @@ pub struct ExportArgs {
     /// Output file
     #[arg(short, long)]
     pub output: Option<PathBuf>,
+    /// Compress output with gzip (appends .gz to the file name)
+    #[arg(long)]
+    pub gzip: bool,
+    /// Write several formats from one snapshot (e.g. json,yaml); requires --output-dir
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        requires = "output_dir",
+        conflicts_with = "output"
+    )]
+    pub formats: Vec<OutputFormat>,
+    /// Directory for `snapshot.<ext>` files written by --formats
+    #[arg(long, value_name = "DIR", requires = "formats")]
+    pub output_dir: Option<PathBuf>,
+    /// Write interfaces as a JSON array of arrays (header row, then one row per interface)
+    #[arg(long, conflicts_with = "formats")]
+    pub table_json: bool,
+    /// Print only the value at this JSON Pointer (e.g. /interfaces/0/name)
+    #[arg(
+        long,
+        value_name = "POINTER",
+        conflicts_with_all = ["formats", "table_json", "output", "gzip"]
+    )]
+    pub query: Option<String>,
+    /// Write a flat JSON object of dotted paths to scalars (e.g. `interfaces.0.ipv4.0`);
+    /// `.`, `=`, backslashes and line breaks in keys are backslash-escaped
+    #[arg(long, conflicts_with_all = ["formats", "table_json", "query"])]
+    pub flat: bool,
+    /// With --flat: write `path=value` lines instead of a JSON object
+    #[arg(long, requires = "flat")]
+    pub flat_kv: bool,
+    /// Columns for --table-json (comma-separated)
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        requires = "table_json",
+        default_values_t = [
+            TableField::Name,
+            TableField::Index,
+            TableField::Type,
+            TableField::State,
+            TableField::Mac,
+            TableField::Mtu,
+            TableField::Ipv4,
+            TableField::Ipv6,
+        ]
+    )]
+    pub fields: Vec<TableField>,
 }
 
 #[derive(Args, Debug)]
 pub struct PublicArgs {
     /// IPv4 only
-    #[arg(long)]
+    #[arg(long, conflicts_with = "v6_only")]
     pub ipv4: bool,
-    /// Timeout seconds
-    #[arg(long, default_value_t = 3)]
-    pub timeout: u64,
+    /// IPv6 only (skip the IPv4 request)
+    #[arg(long)]
+    pub v6_only: bool,
+    /// Print only the bare public IPv4 address (IPv6 with --v6-only)
+    #[arg(long)]
+    pub raw: bool,
+    /// Exit 0 if a public IPv6 address was detected, 1 otherwise (prints nothing)
+    #[arg(long, conflicts_with_all = ["ipv4", "v6_only", "raw"])]
+    pub has_v6: bool,
+    /// With --has-v6: also print `true`/`false`
+    #[arg(long, requires = "has_v6")]
+    pub print: bool,
+    /// Always report ASN/country per address family, even when both match
+    #[arg(long)]
+    pub no_common: bool,
+    /// Append the result to a JSON-lines history file and report whether it changed
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["has_v6", "raw"])]
+    pub history: Option<PathBuf>,
+    /// Use a custom provider from the config file instead of the built-in endpoints
+    #[arg(long, value_name = "NAME", conflicts_with_all = ["ipv4", "v6_only"])]
+    pub provider: Option<String>,
+    /// Request timeout (e.g. 1500ms, 10s; a bare number is seconds)
+    #[arg(long, value_name = "DURATION", default_value = "3", value_parser = parse_duration)]
+    pub timeout: Duration,
+}
+
+/// Parse a humantime duration (`500ms`, `2m`, `1h 30m`); a bare number is seconds
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    if let Ok(secs) = s.parse::<u64>() {
+        return Ok(Duration::from_secs(secs));
+    }
+    humantime::parse_duration(s).map_err(|e| e.to_string())
 }
@@ -0,0 +1,25 @@
+use anyhow::Result;
+
+use crate::cli::{AdminArgs, Cli};
+use crate::collector;
+
+/// Administratively bring the specified interface up or down
+pub fn set_interface_state(_cli: &Cli, args: &AdminArgs, up: bool) -> Result<()> {
+    let state = if up { "up" } else { "down" };
+    if collector::iface::get_interface_by_name(&args.iface).is_none() {
+        anyhow::bail!("Interface '{}' not found", args.iface);
+    }
+    if !collector::admin::is_supported() {
+        anyhow::bail!("Changing interface admin state is not supported on this platform");
+    }
+    if !collector::admin::is_elevated() {
+        anyhow::bail!(
+            "Permission denied: bringing '{}' {} requires root or CAP_NET_ADMIN (try sudo)",
+            args.iface,
+            state
+        );
+    }
+    collector::admin::set_admin_state(&args.iface, up)?;
+    println!("{}: admin state set to {}", args.iface, state);
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use anyhow::Result;
+
+use crate::cli::{ArpArgs, ArpSort, Cli, OutputFormat};
+use crate::collector;
+use crate::renderer::tree::{TreeOptions, print_neighbor_tree};
+
+/// Show the ARP/NDP neighbor cache
+pub fn show_neighbors(cli: &Cli, args: &ArpArgs) -> Result<()> {
+    let mut entries = match &args.iface {
+        Some(name) => collector::neighbor::neighbors_of(name),
+        None => collector::neighbor::collect_neighbors(),
+    };
+    match args.sort {
+        ArpSort::Ip => entries.sort_by_key(|n| n.ip),
+        ArpSort::Iface => entries.sort_by(|a, b| a.iface.cmp(&b.iface).then(a.ip.cmp(&b.ip))),
+    }
+    match cli.format {
+        OutputFormat::Json => crate::renderer::json::print_json(&entries, cli.use_color())?,
+        OutputFormat::Yaml => println!("{}", serde_yaml::to_string(&entries)?),
+        OutputFormat::Tree => print_neighbor_tree(&entries, &TreeOptions::from_cli(cli)),
+    }
+    Ok(())
+}
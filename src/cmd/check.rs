@@ -0,0 +1,215 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use netdev::Interface;
+
+use crate::cli::{CheckArgs, Cli, OutputFormat};
+use crate::collector;
+use crate::config::config;
+use crate::model::check::{CheckReport, Finding};
+use crate::renderer::tree::{TreeOptions, fmt_bps, print_check_tree};
+
+/// Run network diagnostics and report findings
+pub fn run_checks(cli: &Cli, args: &CheckArgs) -> Result<()> {
+    let interfaces = collector::iface::collect_all_interfaces();
+
+    let mut findings = Vec::new();
+    if let Some(default_iface) = interfaces.iter().find(|i| i.default) {
+        findings.extend(check_apipa(default_iface));
+    }
+    findings.extend(check_promiscuous(&interfaces));
+    findings.extend(check_member_mtu(&interfaces));
+    findings.extend(check_half_duplex(&interfaces));
+    findings.extend(check_overlapping_subnets(&interfaces));
+    if args.asymmetric_speed {
+        findings.extend(check_asymmetric_speed(&interfaces));
+    }
+    if let Some(secs) = args.sample_errors {
+        findings.extend(check_link_errors(&interfaces, Duration::from_secs(secs)));
+    }
+
+    let report = CheckReport { findings };
+    match cli.format {
+        OutputFormat::Json => crate::renderer::json::print_json(&report, cli.use_color())?,
+        OutputFormat::Yaml => println!("{}", serde_yaml::to_string(&report)?),
+        OutputFormat::Tree => print_check_tree(&report, &TreeOptions::from_cli(cli)),
+    }
+    Ok(())
+}
+
+/// Warn when the default interface only has APIPA (169.254/16) IPv4 addresses
+fn check_apipa(iface: &Interface) -> Option<Finding> {
+    if iface.ipv4.is_empty() || !iface.ipv4.iter().all(|n| n.addr().is_link_local()) {
+        return None;
+    }
+    let addrs: Vec<String> = iface.ipv4.iter().map(|n| n.addr().to_string()).collect();
+    Some(Finding::warn(
+        "apipa",
+        format!(
+            "default interface {} only has link-local IPv4 {} (DHCP likely failed)",
+            iface.name,
+            addrs.join(", ")
+        ),
+    ))
+}
+
+/// Warn about interfaces in promiscuous mode unless listed in `promiscuous_allowed`
+fn check_promiscuous(interfaces: &[Interface]) -> Vec<Finding> {
+    let allowed = &config().promiscuous_allowed;
+    interfaces
+        .iter()
+        .filter(|i| !allowed.contains(&i.name))
+        .filter(|i| collector::iface::is_promiscuous(i) == Some(true))
+        .map(|i| {
+            Finding::warn(
+                "promiscuous",
+                format!(
+                    "interface {} is in promiscuous mode (possible packet capture)",
+                    i.name
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Warn about up links that negotiated half duplex
+fn check_half_duplex(interfaces: &[Interface]) -> Vec<Finding> {
+    interfaces
+        .iter()
+        .filter(|i| i.is_up())
+        .filter_map(collector::iface::link_mode)
+        .filter(|m| m.duplex.as_deref() == Some("half"))
+        .map(|m| {
+            Finding::warn(
+                "half-duplex",
+                format!(
+                    "interface {} runs half duplex (autoneg {}; check cabling and the switch port)",
+                    m.iface,
+                    match m.autoneg {
+                        Some(true) => "on",
+                        Some(false) => "off",
+                        None => "unknown",
+                    }
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Warn when two interfaces have overlapping IPv4 networks (ambiguous routing)
+fn check_overlapping_subnets(interfaces: &[Interface]) -> Vec<Finding> {
+    collector::iface::find_overlapping_subnets(interfaces)
+        .into_iter()
+        .map(|(a, b, net)| {
+            Finding::warn(
+                "overlapping-subnet",
+                format!(
+                    "interfaces {} and {} have overlapping IPv4 networks ({})",
+                    a, b, net
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Warn when members of the same bridge/bond have differing MTUs
+fn check_member_mtu(interfaces: &[Interface]) -> Vec<Finding> {
+    let mtu_of = |name: &str| interfaces.iter().find(|i| i.name == name)?.mtu;
+    collector::topology::collect_topology(interfaces)
+        .into_iter()
+        .filter(|t| t.kind.is_some() && t.members.len() > 1)
+        .filter_map(|t| {
+            let mtus: Vec<(&String, Option<u32>)> =
+                t.members.iter().map(|m| (m, mtu_of(m))).collect();
+            let first = mtus[0].1;
+            if mtus.iter().all(|(_, mtu)| *mtu == first) {
+                return None;
+            }
+            let members: Vec<String> = mtus
+                .iter()
+                .map(|(m, mtu)| match mtu {
+                    Some(mtu) => format!("{}={}", m, mtu),
+                    None => format!("{}=?", m),
+                })
+                .collect();
+            Some(Finding::warn(
+                "member-mtu",
+                format!(
+                    "{} {} members have differing MTUs: {}",
+                    t.kind.as_deref().unwrap_or_default(),
+                    t.name,
+                    members.join(", ")
+                ),
+            ))
+        })
+        .collect()
+}
+
+/// Warn about interfaces whose RX and TX link speeds differ
+fn check_asymmetric_speed(interfaces: &[Interface]) -> Vec<Finding> {
+    interfaces
+        .iter()
+        .filter(|i| collector::iface::has_asymmetric_speed(i))
+        .map(|i| {
+            Finding::warn(
+                "asymmetric-speed",
+                format!(
+                    "interface {} has asymmetric link speed (TX {}, RX {})",
+                    i.name,
+                    fmt_bps(i.transmit_speed.unwrap_or_default()),
+                    fmt_bps(i.receive_speed.unwrap_or_default())
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Sample error/drop/collision counters twice and warn about each interface
+/// whose counters increased in between (active link problems)
+fn check_link_errors(interfaces: &[Interface], window: Duration) -> Vec<Finding> {
+    let before: Vec<_> = interfaces
+        .iter()
+        .filter_map(|i| Some((i, collector::stats::link_counters(&i.name)?)))
+        .collect();
+    if before.is_empty() {
+        return vec![Finding::ok(
+            "link-errors",
+            "error counters are not available on this platform",
+        )];
+    }
+    std::thread::sleep(window);
+    let findings: Vec<Finding> = before
+        .into_iter()
+        .filter_map(|(iface, first)| {
+            let second = collector::stats::link_counters(&iface.name)?;
+            let increased: Vec<String> = first
+                .error_counters()
+                .iter()
+                .zip(second.error_counters())
+                .filter(|((_, a), (_, b))| b > a)
+                .map(|((name, a), (_, b))| format!("{} +{}", name, b - a))
+                .collect();
+            (!increased.is_empty()).then(|| {
+                Finding::warn(
+                    "link-errors",
+                    format!(
+                        "interface {} counters increased within {}s: {}",
+                        iface.name,
+                        window.as_secs(),
+                        increased.join(", ")
+                    ),
+                )
+            })
+        })
+        .collect();
+    if findings.is_empty() {
+        return vec![Finding::ok(
+            "link-errors",
+            format!(
+                "no error/drop/collision counters increased within {}s",
+                window.as_secs()
+            ),
+        )];
+    }
+    findings
+}
@@ -0,0 +1,114 @@
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use termtree::Tree;
+
+use crate::cli::{Cli, OutputFormat};
+use crate::config::{CONFIG_ENV, config, config_path};
+use crate::renderer::tree::{TreeOptions, tree_label};
+
+/// Settings in effect after merging defaults, config file, env and CLI
+#[derive(Debug, Serialize)]
+struct EffectiveConfig {
+    /// Config file location that was looked up
+    config_file: Option<String>,
+    /// Whether the config file existed and was loaded
+    config_loaded: bool,
+    /// Whether the location came from `$NIFA_CONFIG`
+    config_from_env: bool,
+    aliases: BTreeMap<String, String>,
+    promiscuous_allowed: Vec<String>,
+    format: String,
+    ascii: bool,
+    color: bool,
+    hide: Vec<String>,
+    with_vendor: bool,
+}
+
+/// Print the effective configuration
+pub fn show_config(cli: &Cli) -> Result<()> {
+    let cfg = config();
+    let effective = EffectiveConfig {
+        config_file: cfg
+            .path
+            .clone()
+            .or_else(config_path)
+            .map(|p| p.display().to_string()),
+        config_loaded: cfg.path.is_some(),
+        config_from_env: std::env::var_os(CONFIG_ENV).is_some(),
+        aliases: cfg.aliases.clone(),
+        promiscuous_allowed: cfg.promiscuous_allowed.clone(),
+        format: value_name(&cli.format),
+        ascii: cli.ascii,
+        color: cli.use_color(),
+        hide: cli.hide.iter().map(value_name).collect(),
+        with_vendor: cli.with_vendor,
+    };
+    match cli.format {
+        OutputFormat::Json => crate::renderer::json::print_json(&effective, cli.use_color())?,
+        OutputFormat::Yaml => println!("{}", serde_yaml::to_string(&effective)?),
+        OutputFormat::Tree => print_config_tree(&effective, &TreeOptions::from_cli(cli)),
+    }
+    Ok(())
+}
+
+fn value_name<T: ValueEnum>(v: &T) -> String {
+    v.to_possible_value()
+        .map(|p| p.get_name().to_string())
+        .unwrap_or_default()
+}
+
+fn print_config_tree(effective: &EffectiveConfig, opts: &TreeOptions) {
+    let mut root = Tree::new(tree_label("Effective Configuration"));
+    let file = match &effective.config_file {
+        Some(path) => format!(
+            "{} ({}{})",
+            path,
+            if effective.config_loaded {
+                "loaded"
+            } else {
+                "not found"
+            },
+            if effective.config_from_env {
+                format!(", from ${}", CONFIG_ENV)
+            } else {
+                String::new()
+            }
+        ),
+        None => "(none)".to_string(),
+    };
+    root.push(Tree::new(format!("Config file: {}", file)));
+
+    let mut aliases = Tree::new(tree_label("Aliases"));
+    for (name, alias) in &effective.aliases {
+        aliases.push(Tree::new(format!("{} -> {}", name, alias)));
+    }
+    if effective.aliases.is_empty() {
+        aliases.push(Tree::new(tree_label("(none)")));
+    }
+    root.push(aliases);
+    root.push(Tree::new(format!(
+        "Promiscuous allowed: {}",
+        if effective.promiscuous_allowed.is_empty() {
+            "(none)".to_string()
+        } else {
+            effective.promiscuous_allowed.join(", ")
+        }
+    )));
+
+    root.push(Tree::new(format!("Format: {}", effective.format)));
+    root.push(Tree::new(format!("ASCII: {}", effective.ascii)));
+    root.push(Tree::new(format!("Color: {}", effective.color)));
+    root.push(Tree::new(format!(
+        "Hide: {}",
+        if effective.hide.is_empty() {
+            "(none)".to_string()
+        } else {
+            effective.hide.join(", ")
+        }
+    )));
+    root.push(Tree::new(format!("With vendor: {}", effective.with_vendor)));
+    root.set_glyphs(opts.glyphs());
+    println!("{}", root);
+}
@@ -1,20 +1,54 @@
-use std::{fs, io::Write, path::Path};
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
 
 use crate::cli::{Cli, ExportArgs, OutputFormat};
+use crate::collector::IfaceLookups;
+use crate::model::snapshot::Snapshot;
+use crate::renderer;
 use anyhow::{Context, Result};
+use flate2::{Compression, write::GzEncoder};
+use netdev::Interface;
+use serde::Serialize;
 
 pub fn export_snapshot(cli: &Cli, args: &ExportArgs) -> Result<()> {
-    let snapshot = crate::collector::collect_snapshot()?;
-    let (bytes, ext_default) = match cli.format {
-        OutputFormat::Json | OutputFormat::Tree => {
-            // tree are ignored for export, default to json
-            (serde_json::to_vec_pretty(&snapshot)?, "json")
+    let snapshot = collect(cli)?;
+    if let Some(dir) = &args.output_dir {
+        return export_formats(&snapshot, &args.formats, dir, args.gzip);
+    }
+    if let Some(pointer) = &args.query {
+        return print_query(&snapshot, pointer);
+    }
+    if (args.table_json || args.flat) && cli.template.is_some() {
+        anyhow::bail!("--table-json and --flat cannot be combined with --template");
+    }
+    let (bytes, ext_default) = match &cli.template {
+        None if args.table_json => {
+            let table = renderer::json::interface_table_json(&snapshot.interfaces, &args.fields);
+            (table.into_bytes(), "json")
+        }
+        None if args.flat => {
+            let value = serde_json::to_value(&snapshot)?;
+            if args.flat_kv {
+                (renderer::json::flat_kv(&value).into_bytes(), "txt")
+            } else {
+                (renderer::json::flat_json(&value).into_bytes(), "json")
+            }
         }
-        OutputFormat::Yaml => (serde_yaml::to_string(&snapshot)?.into_bytes(), "yaml"),
+        Some(path) => (
+            renderer::template::render_snapshot(path, &snapshot)?.into_bytes(),
+            "txt",
+        ),
+        None => encode(&snapshot, cli.format)?,
     };
+    let bytes = if args.gzip { gzip(&bytes)? } else { bytes };
     if let Some(path) = &args.output {
-        atomic_write(path, &bytes, ext_default)?;
-        eprintln!("Exported {} bytes to {}", bytes.len(), path.display());
+        let target = output_path(path, ext_default, args.gzip);
+        atomic_write(&target, &bytes)?;
+        eprintln!("Exported {} bytes to {}", bytes.len(), target.display());
     } else {
         // if no output file, write to stdout
         std::io::stdout()
@@ -24,17 +58,182 @@ pub fn export_snapshot(cli: &Cli, args: &ExportArgs) -> Result<()> {
     Ok(())
 }
 
-/// Atomically write data to a file (with default extension if missing)
-fn atomic_write(path: &Path, data: &[u8], ext_default: &str) -> Result<()> {
-    // Add default extension if missing
-    let target = if path.extension().is_none() {
+/// Print the value at a JSON Pointer: scalars bare, objects/arrays as JSON
+fn print_query(snapshot: &Snapshot, pointer: &str) -> Result<()> {
+    if !pointer.is_empty() && !pointer.starts_with('/') {
+        anyhow::bail!("Invalid JSON pointer '{}': must start with '/'", pointer);
+    }
+    let value = serde_json::to_value(snapshot)?;
+    let found = value
+        .pointer(pointer)
+        .with_context(|| format!("No value at JSON pointer '{}'", pointer))?;
+    match found {
+        serde_json::Value::String(s) => println!("{}", s),
+        serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
+            println!("{}", serde_json::to_string_pretty(found)?)
+        }
+        scalar => println!("{}", scalar),
+    }
+    Ok(())
+}
+
+/// Print the snapshot rendered through `--template` to stdout
+pub fn print_template(cli: &Cli) -> Result<()> {
+    let snapshot = collect(cli)?;
+    print_with_template(cli, &snapshot)
+}
+
+/// Snapshot of `interfaces` for `--template` (None without it), so list/show
+/// render what they matched
+pub fn template_snapshot(
+    cli: &Cli,
+    interfaces: &[Interface],
+    lookups: &IfaceLookups,
+) -> Option<Snapshot> {
+    cli.template.as_ref()?;
+    Some(Snapshot::new(
+        crate::collector::sys::system_info(),
+        interfaces.to_vec(),
+        lookups,
+    ))
+}
+
+/// Print `context` rendered through `--template` to stdout
+pub fn print_with_template<T: Serialize>(cli: &Cli, context: &T) -> Result<()> {
+    let Some(path) = &cli.template else {
+        return Ok(());
+    };
+    print!("{}", renderer::template::render_snapshot(path, context)?);
+    Ok(())
+}
+
+/// Collect the snapshot, honoring `--sort-addresses`
+fn collect(cli: &Cli) -> Result<Snapshot> {
+    let mut snapshot = crate::collector::collect_snapshot()?;
+    if cli.sort_addresses {
+        snapshot
+            .interfaces
+            .iter_mut()
+            .for_each(crate::collector::iface::sort_addresses);
+    }
+    Ok(snapshot)
+}
+
+/// Save the snapshot as JSON to `nifa-snapshot-<UTC time>.json` in the current
+/// directory (monitor `e` key), returning the file name
+pub fn save_snapshot_json(snapshot: &Snapshot) -> Result<PathBuf> {
+    // e.g. 20261017T034454Z: no ':' so the name is valid on Windows too
+    let stamp: String = humantime::format_rfc3339_seconds(SystemTime::now())
+        .to_string()
+        .chars()
+        .filter(|c| !matches!(c, '-' | ':'))
+        .collect();
+    let target = PathBuf::from(format!("nifa-snapshot-{}.json", stamp));
+    let (bytes, _) = encode(snapshot, OutputFormat::Json)?;
+    atomic_write(&target, &bytes)?;
+    Ok(target)
+}
+
+/// Write one `snapshot.<ext>` per format into `dir`, all from the same snapshot
+fn export_formats(
+    snapshot: &Snapshot,
+    formats: &[OutputFormat],
+    dir: &Path,
+    gzip_output: bool,
+) -> Result<()> {
+    if formats.iter().any(|f| matches!(f, OutputFormat::Tree)) {
+        anyhow::bail!("--formats supports json and yaml only");
+    }
+    fs::create_dir_all(dir).with_context(|| format!("create {}", dir.display()))?;
+    for format in formats {
+        let (bytes, ext) = encode(snapshot, *format)?;
+        let bytes = if gzip_output { gzip(&bytes)? } else { bytes };
+        let target = output_path(&dir.join("snapshot"), ext, gzip_output);
+        atomic_write(&target, &bytes)?;
+        eprintln!("Exported {} bytes to {}", bytes.len(), target.display());
+    }
+    Ok(())
+}
+
+/// Serialize the snapshot, returning the bytes and the default file extension
+fn encode(snapshot: &Snapshot, format: OutputFormat) -> Result<(Vec<u8>, &'static str)> {
+    Ok(match format {
+        OutputFormat::Json | OutputFormat::Tree => {
+            // tree are ignored for export, default to json
+            (serde_json::to_vec_pretty(snapshot)?, "json")
+        }
+        OutputFormat::Yaml => (serde_yaml::to_string(snapshot)?.into_bytes(), "yaml"),
+    })
+}
+
+/// Compress data with gzip
+fn gzip(data: &[u8]) -> Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data).context("gzip")?;
+    encoder.finish().context("gzip")
+}
+
+/// Resolve the output path (with default extension if missing, `.gz` appended for gzip)
+fn output_path(path: &Path, ext_default: &str, gzip: bool) -> PathBuf {
+    let mut target = if path.extension().is_none() {
         path.with_extension(ext_default)
     } else {
         path.to_path_buf()
     };
+    if gzip && target.extension().is_none_or(|e| e != "gz") {
+        let mut name = target.as_os_str().to_owned();
+        name.push(".gz");
+        target = PathBuf::from(name);
+    }
+    target
+}
 
+/// Atomically write data to a file
+fn atomic_write(target: &Path, data: &[u8]) -> Result<()> {
     let tmp = target.with_extension("tmp");
     fs::write(&tmp, data).with_context(|| format!("write temp {}", tmp.display()))?;
-    fs::rename(&tmp, &target).with_context(|| format!("rename to {}", target.display()))?;
+    fs::rename(&tmp, target).with_context(|| format!("rename to {}", target.display()))?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn gzip_export_round_trips() {
+        let mut iface = netdev::Interface::dummy();
+        iface.name = "eth0".into();
+        iface.ipv4 = vec!["192.168.1.5/24".parse().unwrap()];
+        let snapshot = crate::model::snapshot::Snapshot {
+            sys: Default::default(),
+            stats_basis: crate::model::snapshot::STATS_BASIS_SINCE_BOOT.into(),
+            interfaces: vec![iface],
+            ipv6_lifetimes: Vec::new(),
+            ipv6_ra: Vec::new(),
+            zones: Vec::new(),
+            multicast_groups: Vec::new(),
+            search_domains: Vec::new(),
+            link_modes: Vec::new(),
+        };
+
+        let (bytes, ext) = (serde_json::to_vec_pretty(&snapshot).unwrap(), "json");
+        let base = std::env::temp_dir().join(format!("nifa-test-{}-snapshot", std::process::id()));
+        let target = output_path(&base, ext, true);
+        assert!(target.to_string_lossy().ends_with("snapshot.json.gz"));
+        atomic_write(&target, &gzip(&bytes).unwrap()).unwrap();
+
+        let compressed = fs::read(&target).unwrap();
+        fs::remove_file(&target).unwrap();
+        let mut json = String::new();
+        flate2::read::GzDecoder::new(compressed.as_slice())
+            .read_to_string(&mut json)
+            .unwrap();
+        assert_eq!(json.as_bytes(), bytes.as_slice());
+        let loaded: crate::model::snapshot::Snapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.interfaces.len(), 1);
+        assert_eq!(loaded.interfaces[0].name, "eth0");
+        assert_eq!(loaded.interfaces[0].ipv4, snapshot.interfaces[0].ipv4);
+    }
+}
@@ -1,33 +1,93 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use regex::Regex;
+
 use crate::cli::Cli;
 use crate::cli::ListArgs;
+use crate::cmd::Timing;
 use crate::collector;
+use crate::model::iface::{ChangeKind, InterfaceChange};
+use crate::model::snapshot::Snapshot;
 use crate::renderer;
 use netdev::Interface;
+use netdev::ipnet::IpNet;
 
 /// Default action with no subcommand
-pub fn show_interfaces(cli: &Cli) {
-    let interfaces: Vec<Interface> = if cli.default {
+pub async fn show_interfaces(cli: &Cli) {
+    let mut timing = Timing::start(cli);
+    let mut interfaces: Vec<Interface> = if cli.default {
         collector::iface::get_default_interface()
             .into_iter()
             .collect()
     } else {
-        collector::iface::collect_all_interfaces()
+        collector::iface::collect_all_interfaces_default_first()
     };
+    if cli.sort_addresses {
+        interfaces
+            .iter_mut()
+            .for_each(collector::iface::sort_addresses);
+    }
+    let lookups = tree_lookups(cli, &interfaces);
+    timing.collected(interfaces.len());
     // Render output
     match cli.format {
-        crate::cli::OutputFormat::Tree => renderer::tree::print_interface_tree(&interfaces),
-        crate::cli::OutputFormat::Json => renderer::json::print_interface_json(&interfaces),
+        crate::cli::OutputFormat::Tree => renderer::tree::print_interface_tree(
+            &interfaces,
+            &lookups,
+            &renderer::tree::TreeOptions::from_cli(cli),
+        ),
+        crate::cli::OutputFormat::Json => {
+            renderer::json::print_interface_json(&interfaces, cli.use_color())
+        }
         crate::cli::OutputFormat::Yaml => renderer::yaml::print_interface_yaml(&interfaces),
     }
+    timing.finish();
+    if cli.probe {
+        let connectivity = collector::connectivity::probe_connectivity().await;
+        let line = renderer::tree::connectivity_line(&connectivity);
+        match cli.format {
+            crate::cli::OutputFormat::Tree => println!("{}", line),
+            // Keep stdout parseable for JSON/YAML
+            _ => eprintln!("{}", line),
+        }
+    }
 }
 
-pub fn list_interfaces(cli: &Cli, args: &ListArgs) {
-    let mut interfaces: Vec<Interface> = collector::iface::collect_all_interfaces();
+pub fn list_interfaces(cli: &Cli, args: &ListArgs) -> Result<()> {
+    // Compile regex and read name lists before collection so bad input fails fast
+    let name_regex = compile_name_regex(args.name_regex.as_deref())?;
+    let name_lists = NameLists::load(args.include_file.as_deref(), args.exclude_file.as_deref())?;
+    if cli.template.is_some() && (args.changed_since.is_some() || args.sum_by_type || args.topology)
+    {
+        anyhow::bail!(
+            "--changed-since, --sum-by-type and --topology cannot be combined with --template"
+        );
+    }
+
+    let mut timing = Timing::start(cli);
+    let mut interfaces: Vec<Interface> = collector::iface::collect_all_interfaces_default_first();
+    // Names before filtering, so filtered-out interfaces are not reported as removed
+    let present: Vec<String> = interfaces.iter().map(|i| i.name.clone()).collect();
+
+    if cli.sort_addresses {
+        interfaces
+            .iter_mut()
+            .for_each(collector::iface::sort_addresses);
+    }
 
     // Apply filters
+    if cli.default {
+        interfaces.retain(|iface| iface.default);
+    }
     if let Some(name_like) = &args.name_like {
         interfaces.retain(|iface| iface.name.contains(name_like));
     }
+    if let Some(re) = &name_regex {
+        interfaces.retain(|iface| re.is_match(&iface.name));
+    }
+    interfaces.retain(|iface| !is_excluded(&iface.name, &args.exclude_name));
+    interfaces.retain(|iface| name_lists.allows(&iface.name));
     if args.up {
         interfaces.retain(|iface| iface.oper_state == netdev::interface::OperState::Up);
     }
@@ -46,11 +106,353 @@ pub fn list_interfaces(cli: &Cli, args: &ListArgs) {
     if args.ipv6 {
         interfaces.retain(|iface| !iface.ipv6.is_empty());
     }
+    if let Some(net) = &args.in_network {
+        interfaces.retain(|iface| in_network(iface, net));
+    }
+    interfaces.retain(|iface| gateway_filter_allows(iface, args.has_gateway, args.no_gateway));
 
-    // Render output
+    // --changed-since reports the changes instead of the interfaces
+    let changes = match &args.changed_since {
+        Some(path) => {
+            let snapshot = Snapshot::from_path(path)?;
+            Some((
+                path,
+                changed_since(&interfaces, &present, snapshot.interfaces),
+            ))
+        }
+        None => None,
+    };
+    let matched = changes
+        .as_ref()
+        .map_or(interfaces.len(), |(_, changes)| changes.len());
+
+    let lookups = tree_lookups(cli, &interfaces);
+    let template = crate::cmd::export::template_snapshot(cli, &interfaces, &lookups);
+    let links = (args.topology && matches!(cli.format, crate::cli::OutputFormat::Tree))
+        .then(|| collector::topology::collect_topology(&interfaces));
+    timing.collected(interfaces.len());
+    if args.count {
+        // Count only, regardless of output format
+        println!("{}", matched);
+    } else if let Some(snapshot) = &template {
+        crate::cmd::export::print_with_template(cli, snapshot)?;
+    } else if let Some((path, changes)) = &changes {
+        render_changes(cli, path, changes, &lookups)?;
+    } else if args.sum_by_type {
+        render_type_totals(cli, args, &interfaces)?;
+    } else {
+        render_list(cli, &interfaces, links.as_deref(), &lookups);
+    }
+    timing.finish();
+    if args.fail_on_empty && matched == 0 {
+        return Err(crate::cmd::ExitStatus(1).into());
+    }
+    Ok(())
+}
+
+/// Render `--changed-since` results in the selected output format
+fn render_changes(
+    cli: &Cli,
+    path: &Path,
+    changes: &[InterfaceChange],
+    lookups: &collector::IfaceLookups,
+) -> Result<()> {
     match cli.format {
-        crate::cli::OutputFormat::Tree => renderer::tree::print_interface_tree(&interfaces),
-        crate::cli::OutputFormat::Json => renderer::json::print_interface_json(&interfaces),
-        crate::cli::OutputFormat::Yaml => renderer::yaml::print_interface_yaml(&interfaces),
+        crate::cli::OutputFormat::Tree => renderer::tree::print_interface_change_tree(
+            changes,
+            path,
+            lookups,
+            &renderer::tree::TreeOptions::from_cli(cli),
+        ),
+        crate::cli::OutputFormat::Json => renderer::json::print_json(changes, cli.use_color())?,
+        crate::cli::OutputFormat::Yaml => println!("{}", serde_yaml::to_string(changes)?),
+    }
+    Ok(())
+}
+
+/// Lookups for the tree view and `--template`; JSON/YAML list the interfaces only
+fn tree_lookups(cli: &Cli, interfaces: &[Interface]) -> collector::IfaceLookups {
+    match cli.format {
+        _ if cli.template.is_some() => collector::IfaceLookups::collect(interfaces),
+        crate::cli::OutputFormat::Tree => collector::IfaceLookups::collect(interfaces),
+        _ => Default::default(),
+    }
+}
+
+/// Render the filtered interfaces in the selected output format;
+/// `links` is the collected topology when --topology applies
+fn render_list(
+    cli: &Cli,
+    interfaces: &[Interface],
+    links: Option<&[crate::model::topology::LinkTopology]>,
+    lookups: &collector::IfaceLookups,
+) {
+    match cli.format {
+        crate::cli::OutputFormat::Tree if let Some(links) = links => {
+            renderer::tree::print_interface_topology_tree(
+                interfaces,
+                links,
+                lookups,
+                &renderer::tree::TreeOptions::from_cli(cli),
+            )
+        }
+        crate::cli::OutputFormat::Tree => renderer::tree::print_interface_tree(
+            interfaces,
+            lookups,
+            &renderer::tree::TreeOptions::from_cli(cli),
+        ),
+        crate::cli::OutputFormat::Json => {
+            renderer::json::print_interface_json(interfaces, cli.use_color())
+        }
+        crate::cli::OutputFormat::Yaml => renderer::yaml::print_interface_yaml(interfaces),
+    }
+}
+
+/// Render per-type traffic totals of the filtered interfaces
+fn render_type_totals(cli: &Cli, args: &ListArgs, interfaces: &[Interface]) -> Result<()> {
+    let groups = collector::iface::totals_by_type(interfaces);
+    match cli.format {
+        crate::cli::OutputFormat::Tree => renderer::tree::print_type_totals_tree(
+            &groups,
+            args.unit,
+            &renderer::tree::TreeOptions::from_cli(cli),
+        ),
+        crate::cli::OutputFormat::Json => renderer::json::print_json(&groups, cli.use_color())?,
+        crate::cli::OutputFormat::Yaml => println!("{}", serde_yaml::to_string(&groups)?),
+    }
+    Ok(())
+}
+
+/// Interfaces that are new or changed relative to the snapshot, plus snapshot
+/// interfaces no longer present on the host. Counters are ignored.
+fn changed_since(
+    current: &[Interface],
+    present: &[String],
+    previous: Vec<Interface>,
+) -> Vec<InterfaceChange> {
+    let mut changes: Vec<InterfaceChange> = current
+        .iter()
+        .filter_map(|iface| {
+            let change = match previous.iter().find(|p| p.name == iface.name) {
+                None => ChangeKind::New,
+                Some(prev) if comparable(prev) != comparable(iface) => ChangeKind::Changed,
+                Some(_) => return None,
+            };
+            Some(InterfaceChange {
+                change,
+                iface: iface.clone(),
+            })
+        })
+        .collect();
+    changes.extend(
+        previous
+            .into_iter()
+            .filter(|p| !present.contains(&p.name))
+            .map(|iface| InterfaceChange {
+                change: ChangeKind::Removed,
+                iface,
+            }),
+    );
+    changes
+}
+
+/// Serialized form used for comparison: addresses sorted, volatile counters dropped
+fn comparable(iface: &Interface) -> serde_json::Value {
+    let mut value =
+        serde_json::to_value(collector::iface::with_sorted_addresses(iface)).unwrap_or_default();
+    if let Some(obj) = value.as_object_mut() {
+        obj.remove("stats");
+    }
+    value
+}
+
+/// Whether any address of the interface lies inside `net` (same family only)
+fn in_network(iface: &Interface, net: &IpNet) -> bool {
+    match net {
+        IpNet::V4(net) => iface.ipv4.iter().any(|a| net.contains(&a.addr())),
+        IpNet::V6(net) => iface.ipv6.iter().any(|a| net.contains(&a.addr())),
+    }
+}
+
+/// Whether `name` contains any of the `--exclude-name` substrings
+pub fn is_excluded(name: &str, excludes: &[String]) -> bool {
+    excludes.iter().any(|x| name.contains(x.as_str()))
+}
+
+/// Interface names from `--include-file` / `--exclude-file`
+#[derive(Debug, Default)]
+pub struct NameLists {
+    include: Option<Vec<String>>,
+    exclude: Vec<String>,
+}
+
+impl NameLists {
+    pub fn load(include: Option<&Path>, exclude: Option<&Path>) -> Result<Self> {
+        Ok(NameLists {
+            include: include.map(read_name_list).transpose()?,
+            exclude: exclude.map(read_name_list).transpose()?.unwrap_or_default(),
+        })
+    }
+
+    /// Whether `name` passes the allowlist (if any) and matches no denylist entry
+    pub fn allows(&self, name: &str) -> bool {
+        self.include
+            .as_ref()
+            .is_none_or(|list| list.iter().any(|p| glob_match(p, name)))
+            && !self.exclude.iter().any(|p| glob_match(p, name))
+    }
+}
+
+/// Read one interface name or glob per line; blank lines and `#` comments are skipped
+fn read_name_list(path: &Path) -> Result<Vec<String>> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    Ok(text
+        .lines()
+        .map(|l| l.split('#').next().unwrap_or("").trim())
+        .filter(|l| !l.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Minimal glob: `*` matches any run of characters, `?` exactly one
+pub fn glob_match(pattern: &str, name: &str) -> bool {
+    let (p, n): (Vec<char>, Vec<char>) = (pattern.chars().collect(), name.chars().collect());
+    let (mut pi, mut ni) = (0, 0);
+    // Position of the last `*` and the name index it is currently matched up to
+    let mut star: Option<(usize, usize)> = None;
+    while ni < n.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == n[ni]) {
+            pi += 1;
+            ni += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some((pi, ni));
+            pi += 1;
+        } else if let Some((sp, sn)) = star {
+            // Let the last `*` swallow one more character
+            pi = sp + 1;
+            ni = sn + 1;
+            star = Some((sp, sn + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|c| *c == '*')
+}
+
+/// Compile the `--name-regex` pattern
+pub fn compile_name_regex(pattern: Option<&str>) -> Result<Option<Regex>> {
+    pattern
+        .map(|p| Regex::new(p).with_context(|| format!("invalid --name-regex pattern '{}'", p)))
+        .transpose()
+}
+
+/// `--has-gateway` / `--no-gateway`: whether the interface passes (both unset lets all through)
+fn gateway_filter_allows(iface: &Interface, has_gateway: bool, no_gateway: bool) -> bool {
+    match iface.gateway {
+        Some(_) => !no_gateway,
+        None => !has_gateway,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gateway_filter() {
+        let mut routed = Interface::dummy();
+        routed.gateway = Some(netdev::NetworkDevice::new());
+        let isolated = Interface::dummy();
+
+        // No flag: everything passes
+        assert!(gateway_filter_allows(&routed, false, false));
+        assert!(gateway_filter_allows(&isolated, false, false));
+        // --has-gateway
+        assert!(gateway_filter_allows(&routed, true, false));
+        assert!(!gateway_filter_allows(&isolated, true, false));
+        // --no-gateway
+        assert!(!gateway_filter_allows(&routed, false, true));
+        assert!(gateway_filter_allows(&isolated, false, true));
+    }
+
+    #[test]
+    fn is_excluded_matches_any_substring() {
+        let excludes = vec!["docker".to_string(), "veth".to_string()];
+        assert!(is_excluded("docker0", &excludes));
+        assert!(is_excluded("br-docker1", &excludes));
+        assert!(is_excluded("veth12ab", &excludes));
+        assert!(!is_excluded("eth0", &excludes));
+        // Case-sensitive, and no excludes hide nothing
+        assert!(!is_excluded("Docker0", &excludes));
+        assert!(!is_excluded("docker0", &[]));
+    }
+
+    fn iface_with(v4: &[&str], v6: &[&str]) -> Interface {
+        let mut iface = Interface::dummy();
+        iface.ipv4 = v4.iter().map(|n| n.parse().unwrap()).collect();
+        iface.ipv6 = v6.iter().map(|n| n.parse().unwrap()).collect();
+        iface
+    }
+
+    #[test]
+    fn in_network_ipv4_inside_outside_and_boundary() {
+        let net: IpNet = "192.168.1.0/24".parse().unwrap();
+        assert!(in_network(&iface_with(&["192.168.1.5/24"], &[]), &net));
+        assert!(!in_network(&iface_with(&["192.168.2.5/24"], &[]), &net));
+        // First and last address of the range are inside, the next one is not
+        assert!(in_network(&iface_with(&["192.168.1.0/32"], &[]), &net));
+        assert!(in_network(&iface_with(&["192.168.1.255/32"], &[]), &net));
+        assert!(!in_network(&iface_with(&["192.168.2.0/32"], &[]), &net));
+        // Any one matching address is enough
+        assert!(in_network(
+            &iface_with(&["10.0.0.1/8", "192.168.1.9/24"], &[]),
+            &net
+        ));
+    }
+
+    #[test]
+    fn in_network_ipv6_and_family_mismatch() {
+        let net: IpNet = "2001:db8::/32".parse().unwrap();
+        assert!(in_network(&iface_with(&[], &["2001:db8:1::5/64"]), &net));
+        assert!(in_network(
+            &iface_with(&[], &["2001:db8:ffff:ffff:ffff:ffff:ffff:ffff/128"]),
+            &net
+        ));
+        assert!(!in_network(&iface_with(&[], &["2001:db9::1/64"]), &net));
+        // An IPv4 address never matches an IPv6 network and vice versa
+        assert!(!in_network(&iface_with(&["192.168.1.5/24"], &[]), &net));
+        let v4: IpNet = "0.0.0.0/0".parse().unwrap();
+        assert!(!in_network(&iface_with(&[], &["2001:db8::1/64"]), &v4));
+    }
+
+    #[test]
+    fn glob_star_and_question_mark() {
+        assert!(glob_match("eth*", "eth0"));
+        assert!(glob_match("*", "wlan0"));
+        assert!(glob_match("*0", "docker0"));
+        assert!(glob_match("v*h*", "veth1a2b"));
+        assert!(glob_match("eth?", "eth1"));
+        assert!(!glob_match("eth?", "eth10"));
+        assert!(!glob_match("en?s*", "enp3s0"));
+        assert!(glob_match("en??s*", "enp3s0"));
+    }
+
+    #[test]
+    fn glob_is_anchored_at_both_ends() {
+        assert!(glob_match("eth0", "eth0"));
+        assert!(!glob_match("eth", "eth0"));
+        assert!(!glob_match("th0", "eth0"));
+        assert!(!glob_match("eth0", "veth0"));
+    }
+
+    #[test]
+    fn glob_empty_and_overlong_patterns() {
+        assert!(glob_match("", ""));
+        assert!(!glob_match("", "eth0"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("eth0x", "eth0"));
+        assert!(!glob_match("eth0?", "eth0"));
+        assert!(glob_match("eth0*", "eth0"));
     }
 }
@@ -1,6 +1,70 @@
+pub mod admin;
+pub mod arp;
+pub mod check;
+pub mod config;
 pub mod export;
 pub mod list;
 pub mod monitor;
 pub mod os;
 pub mod public;
 pub mod show;
+pub mod validate;
+pub mod vpn;
+pub mod wait;
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::cli::Cli;
+
+/// Non-zero exit status for an outcome the command already reported, such as
+/// `list --fail-on-empty` matching nothing. `main` exits with it and prints nothing.
+#[derive(Debug)]
+pub struct ExitStatus(pub i32);
+
+impl fmt::Display for ExitStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "exit status {}", self.0)
+    }
+}
+
+impl std::error::Error for ExitStatus {}
+
+/// `--timing`: measures collection and rendering, reported on stderr
+pub struct Timing {
+    enabled: bool,
+    start: Instant,
+    collected: Option<(usize, Duration)>,
+}
+
+impl Timing {
+    pub fn start(cli: &Cli) -> Self {
+        Timing {
+            enabled: cli.timing,
+            start: Instant::now(),
+            collected: None,
+        }
+    }
+
+    /// Mark the end of collection, after the interface lookups (and topology,
+    /// system info for templates); rendering is measured from here
+    pub fn collected(&mut self, interfaces: usize) {
+        self.collected = Some((interfaces, self.start.elapsed()));
+    }
+
+    /// e.g. "collected 42 interfaces in 18ms, rendered in 2ms"
+    pub fn finish(self) {
+        if !self.enabled {
+            return;
+        }
+        let total = self.start.elapsed();
+        let (count, collect) = self.collected.unwrap_or((0, total));
+        eprintln!(
+            "collected {} interface{} in {}ms, rendered in {}ms",
+            count,
+            if count == 1 { "" } else { "s" },
+            collect.as_millis(),
+            total.saturating_sub(collect).as_millis()
+        );
+    }
+}
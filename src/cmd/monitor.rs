@@ -1,8 +1,13 @@
-use std::collections::HashMap;
-use std::io::{self};
-use std::time::{Duration, Instant};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::io::{self, Write};
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::ValueEnum;
 use crossterm::event::KeyEventKind;
 use crossterm::{
@@ -11,7 +16,7 @@ use crossterm::{
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use humansize::{BINARY, format_size};
-use ratatui::text::Text;
+use ratatui::text::{Line, Text};
 use ratatui::widgets::{Paragraph, Wrap};
 use ratatui::{
     Terminal,
@@ -21,12 +26,27 @@ use ratatui::{
     text::Span,
     widgets::{Block, Borders, Clear, Row, Table},
 };
+use regex::Regex;
+use serde::Serialize;
 use termtree::Tree;
 
 use crate::cli::Cli;
-use crate::cli::MonitorArgs;
+use crate::cli::{MonitorArgs, MonitorLayout, MonitorOutput, Section};
+use crate::cmd::export::save_snapshot_json;
+use crate::cmd::list::{NameLists, compile_name_regex, is_excluded};
+use crate::collector::connectivity::probe_gateway;
 use crate::collector::iface::collect_all_interfaces;
-use crate::renderer::tree::{fmt_bps, fmt_flags, tree_label};
+use crate::collector::remote::fetch_snapshot_ssh;
+use crate::collector::stats::{StatsReplay, link_counters};
+use crate::collector::{IfaceLookups, collect_snapshot};
+use crate::config::{alias_of, display_name};
+use crate::model::neighbor::NeighborEntry;
+use crate::model::snapshot::Snapshot;
+use crate::renderer::plot::{RateSeries, render_rate_png};
+use crate::renderer::tree::{
+    fmt_bps, fmt_flags, gateway_ipv6_label, gateway_mac_label, ipv4_label, ipv6_label,
+    link_speed_label, tree_label, type_label,
+};
 
 #[derive(Clone, Copy, Debug, ValueEnum)]
 pub enum SortKey {
@@ -47,34 +67,396 @@ impl SortKey {
             SortKey::Tx => SortKey::Total,
         }
     }
+
+    /// Direct selection via number keys `1`..`5`
+    fn from_digit(c: char) -> Option<Self> {
+        match c {
+            '1' => Some(SortKey::Total),
+            '2' => Some(SortKey::TotalRx),
+            '3' => Some(SortKey::TotalTx),
+            '4' => Some(SortKey::Rx),
+            '5' => Some(SortKey::Tx),
+            _ => None,
+        }
+    }
 }
 
-#[derive(Clone, Copy, Debug, ValueEnum)]
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
 pub enum Unit {
+    #[default]
     Bytes,
     Bits,
 }
 
-impl Default for Unit {
-    fn default() -> Self {
-        Unit::Bytes
-    }
+/// What the rate and total columns count
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Metric {
+    #[default]
+    Bytes,
+    Packets,
 }
 
 #[derive(Debug, Clone)]
-struct StatPoint {
-    rx_bytes: u64,
-    tx_bytes: u64,
-    ts: Instant,
+pub struct StatPoint {
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    /// Packet counters (0 where the platform does not expose them)
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub ts: Instant,
+}
+
+impl StatPoint {
+    /// Take a sample from the interface's current stats
+    pub fn from_iface(iface: &netdev::Interface) -> Option<Self> {
+        Self::at(iface, Instant::now())
+    }
+
+    /// Sample of a remote or replayed interface: byte counters only, since
+    /// packet counters come from local sysfs
+    fn at_remote(iface: &netdev::Interface, ts: Instant) -> Option<Self> {
+        let st = iface.stats.as_ref()?;
+        Some(StatPoint {
+            rx_bytes: st.rx_bytes,
+            tx_bytes: st.tx_bytes,
+            rx_packets: 0,
+            tx_packets: 0,
+            ts,
+        })
+    }
+
+    /// Take a sample stamped with the given time
+    fn at(iface: &netdev::Interface, ts: Instant) -> Option<Self> {
+        let st = iface.stats.as_ref()?;
+        let counters = link_counters(&iface.name).unwrap_or_default();
+        Some(StatPoint {
+            rx_bytes: st.rx_bytes,
+            tx_bytes: st.tx_bytes,
+            rx_packets: counters.rx_packets,
+            tx_packets: counters.tx_packets,
+            ts,
+        })
+    }
 }
 
 #[derive(Debug, Default, Clone)]
-struct Rate {
-    rx_per_s: f64,
-    tx_per_s: f64,
+pub struct Rate {
+    pub rx_per_s: f64,
+    pub tx_per_s: f64,
+    pub rx_pkts_per_s: f64,
+    pub tx_pkts_per_s: f64,
 }
 
-#[derive(Debug)]
+impl Rate {
+    /// Per-second rate between two samples (counter resets yield 0)
+    pub fn between(prev: &StatPoint, now: &StatPoint) -> Self {
+        let dt = now.ts.duration_since(prev.ts).as_secs_f64().max(0.001);
+        Rate {
+            rx_per_s: (now.rx_bytes.saturating_sub(prev.rx_bytes) as f64) / dt,
+            tx_per_s: (now.tx_bytes.saturating_sub(prev.tx_bytes) as f64) / dt,
+            rx_pkts_per_s: (now.rx_packets.saturating_sub(prev.rx_packets) as f64) / dt,
+            tx_pkts_per_s: (now.tx_packets.saturating_sub(prev.tx_packets) as f64) / dt,
+        }
+    }
+}
+
+/// Last gateway probe result for one address family
+#[derive(Clone, Copy, Debug)]
+enum Reach {
+    Pending,
+    Rtt(Duration),
+    Unreachable,
+}
+
+impl Reach {
+    fn label(self) -> String {
+        match self {
+            Reach::Pending => "Reachability: probing…".to_string(),
+            Reach::Rtt(rtt) => format!("Reachability: {:.1} ms", rtt.as_secs_f64() * 1000.0),
+            Reach::Unreachable => "Reachability: unreachable".to_string(),
+        }
+    }
+}
+
+/// Gateway reachability per family (None = no gateway of that family)
+#[derive(Clone, Copy, Debug, Default)]
+struct GatewayReach {
+    v4: Option<Reach>,
+    v6: Option<Reach>,
+}
+
+/// Background thread probing the gateways of the interface shown in the
+/// detail popup. Dropping it stops the thread.
+struct GatewayProber {
+    index: u32,
+    state: Arc<Mutex<GatewayReach>>,
+    stop: Arc<AtomicBool>,
+}
+
+impl GatewayProber {
+    /// Start probing once per `tick`; None if the interface has no gateway
+    fn spawn(iface: &netdev::Interface, tick: Duration) -> Option<Self> {
+        let gw = iface.gateway.as_ref()?;
+        let v4 = gw.ipv4.first().map(|ip| IpAddr::V4(*ip));
+        let v6 = gw.ipv6.first().map(|ip| IpAddr::V6(*ip));
+        if v4.is_none() && v6.is_none() {
+            return None;
+        }
+        let state = Arc::new(Mutex::new(GatewayReach {
+            v4: v4.map(|_| Reach::Pending),
+            v6: v6.map(|_| Reach::Pending),
+        }));
+        let stop = Arc::new(AtomicBool::new(false));
+        let index = iface.index;
+        let (thread_state, thread_stop) = (state.clone(), stop.clone());
+        std::thread::spawn(move || {
+            let probe = |ip: IpAddr| match probe_gateway(ip, index, tick) {
+                Some(rtt) => Reach::Rtt(rtt),
+                None => Reach::Unreachable,
+            };
+            while !thread_stop.load(Ordering::Relaxed) {
+                let started = Instant::now();
+                let reach = GatewayReach {
+                    v4: v4.map(probe),
+                    v6: v6.map(probe),
+                };
+                if thread_stop.load(Ordering::Relaxed) {
+                    break;
+                }
+                if let Ok(mut s) = thread_state.lock() {
+                    *s = reach;
+                }
+                // Sleep in short steps so closing the popup stops us quickly
+                while started.elapsed() < tick && !thread_stop.load(Ordering::Relaxed) {
+                    std::thread::sleep(Duration::from_millis(100));
+                }
+            }
+        });
+        Some(Self { index, state, stop })
+    }
+
+    fn reach(&self) -> GatewayReach {
+        self.state.lock().map(|s| *s).unwrap_or_default()
+    }
+}
+
+impl Drop for GatewayProber {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Background thread fetching the `--ssh` snapshot once per tick, so a slow
+/// or hanging ssh never freezes the TUI. Dropping it stops the thread.
+struct RemoteFetcher {
+    results: Receiver<(Instant, Result<Snapshot>)>,
+    retry: Sender<()>,
+}
+
+impl RemoteFetcher {
+    fn spawn(target: String, tick: Duration) -> Self {
+        let (result_tx, results) = mpsc::channel();
+        let (retry, retry_rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            loop {
+                let started = Instant::now();
+                let fetched = fetch_snapshot_ssh(&target);
+                if result_tx.send((Instant::now(), fetched)).is_err() {
+                    break;
+                }
+                // Wait out the tick unless a retry is requested
+                match retry_rx.recv_timeout(tick.saturating_sub(started.elapsed())) {
+                    Ok(()) | Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+        });
+        Self { results, retry }
+    }
+
+    /// Newest result since the last call (None while a fetch is in flight),
+    /// stamped with the time it completed
+    fn latest(&self) -> Option<(Instant, Result<Snapshot>)> {
+        self.results.try_iter().last()
+    }
+
+    /// Start the next fetch now instead of at the end of the tick
+    fn retry_now(&self) {
+        let _ = self.retry.send(());
+    }
+}
+
+/// `e` key: collects (locally) and saves a snapshot on a background thread so
+/// the table keeps ticking; one save runs at a time
+#[derive(Default)]
+struct SnapshotSaver {
+    pending: Option<Receiver<Result<PathBuf>>>,
+}
+
+impl SnapshotSaver {
+    /// Start saving `remote`, or a fresh local snapshot when None.
+    /// False while an earlier save is still running.
+    fn start(&mut self, remote: Option<Snapshot>) -> bool {
+        if self.pending.is_some() {
+            return false;
+        }
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let saved = match remote {
+                Some(snapshot) => save_snapshot_json(&snapshot),
+                None => collect_snapshot().and_then(|s| save_snapshot_json(&s)),
+            };
+            let _ = tx.send(saved);
+        });
+        self.pending = Some(rx);
+        true
+    }
+
+    /// Result of the running save once it completed
+    fn finished(&mut self) -> Option<Result<PathBuf>> {
+        let result = match self.pending.as_ref()?.try_recv() {
+            Ok(result) => result,
+            Err(mpsc::TryRecvError::Empty) => return None,
+            Err(mpsc::TryRecvError::Disconnected) => Err(anyhow::anyhow!("save thread exited")),
+        };
+        self.pending = None;
+        Some(result)
+    }
+}
+
+/// Keybindings listed in the `?` help popup
+const KEYBINDINGS: &[(&str, &str)] = &[
+    ("q / Ctrl+C", "Quit"),
+    ("o", "Cycle sort key"),
+    ("1-5", "Sort by Total / Total RX / Total TX / RX/s / TX/s"),
+    ("b / B", "Toggle bytes/bits"),
+    ("r", "Rescan interfaces"),
+    ("e", "Save a JSON snapshot to the current directory"),
+    ("↑/↓ w/s", "Select interface (scroll in details)"),
+    ("Enter", "Open interface details"),
+    ("Esc", "Close popup"),
+    ("?", "Toggle this help"),
+];
+
+/// Shortest accepted --interval
+const MIN_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Most samples kept per interface for --avg-window (an hour at the shortest interval)
+const MAX_WINDOW_TICKS: usize = 36_000;
+
+/// Span of rate history kept for --plot (the chart shows the last hour)
+const PLOT_WINDOW: Duration = Duration::from_secs(3600);
+
+/// How long a status message stays in the header
+const FLASH_DURATION: Duration = Duration::from_secs(3);
+
+/// Short-lived status message shown in the table header
+struct Flash {
+    text: String,
+    error: bool,
+    until: Instant,
+}
+
+impl Flash {
+    fn new(text: String, error: bool) -> Self {
+        Flash {
+            text,
+            error,
+            until: Instant::now() + FLASH_DURATION,
+        }
+    }
+
+    fn style(&self, use_color: bool) -> Style {
+        let style = Style::default().add_modifier(Modifier::BOLD);
+        match (use_color, self.error) {
+            (false, _) => style,
+            (true, true) => style.fg(Color::Red),
+            (true, false) => style.fg(Color::Green),
+        }
+    }
+}
+
+/// --bell / --notify: fires once when an interface crosses --high-threshold and
+/// re-arms only after its rate drops back below it
+struct Alerts {
+    bell: bool,
+    notify: bool,
+    threshold_bps: u64,
+    /// Interfaces currently at or above the threshold
+    above: HashSet<String>,
+}
+
+impl Alerts {
+    fn new(args: &MonitorArgs) -> Self {
+        Alerts {
+            bell: args.bell,
+            notify: args.notify,
+            threshold_bps: args.high_threshold,
+            above: HashSet::new(),
+        }
+    }
+
+    /// Record the interface's current rate (bytes/s); returns the alert text on a new crossing
+    fn observe(&mut self, iface: &str, rate: &Rate) -> Option<String> {
+        if !self.bell && !self.notify {
+            return None;
+        }
+        if (rate.rx_per_s + rate.tx_per_s) * 8.0 < self.threshold_bps as f64 {
+            self.above.remove(iface);
+            return None;
+        }
+        if !self.above.insert(iface.to_string()) {
+            return None;
+        }
+        let mut text = format!(
+            "{} above {}: RX {} TX {}",
+            display_name(iface),
+            human_rate(self.threshold_bps as f64 / 8.0, Unit::Bits),
+            human_rate(rate.rx_per_s, Unit::Bits),
+            human_rate(rate.tx_per_s, Unit::Bits)
+        );
+        if self.bell {
+            // stderr keeps ndjson/influx output on stdout clean
+            let mut stderr = io::stderr();
+            let _ = stderr.write_all(b"\x07").and_then(|_| stderr.flush());
+        }
+        if self.notify
+            && let Err(e) = notify_desktop("nifa: traffic alert", &text)
+        {
+            text.push_str(&format!(" (notification failed: {:#})", e));
+        }
+        Some(text)
+    }
+}
+
+/// Show a desktop notification without waiting for the notifier to exit
+fn notify_desktop(summary: &str, body: &str) -> Result<()> {
+    let mut cmd = if cfg!(target_os = "macos") {
+        let quote = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+        let mut cmd = std::process::Command::new("osascript");
+        cmd.arg("-e").arg(format!(
+            "display notification \"{}\" with title \"{}\"",
+            quote(body),
+            quote(summary)
+        ));
+        cmd
+    } else {
+        let mut cmd = std::process::Command::new("notify-send");
+        cmd.arg(summary).arg(body);
+        cmd
+    };
+    let mut child = cmd
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .context("failed to run the desktop notifier")?;
+    // Reap in the background so slow notifiers don't stall the tick
+    std::thread::spawn(move || child.wait());
+    Ok(())
+}
+
+#[derive(Debug, Default)]
 struct RowData {
     index: u32,
     name: String,
@@ -82,15 +464,94 @@ struct RowData {
     total: u64,
     total_tx: u64,
     total_rx: u64,
+    loopback: bool,
     rx: f64,
     tx: f64,
+    rx_pps: f64,
+    tx_pps: f64,
+    /// Bytes moved since the previous tick
+    rx_delta: u64,
+    tx_delta: u64,
+    /// Average frame size (bytes/packet) over the last tick; None if no packets moved
+    rx_avg_frame: Option<f64>,
+    tx_avg_frame: Option<f64>,
+    /// The interface exposes no counters (byte/rate columns show "—")
+    no_stats: bool,
 }
 
-pub fn monitor_interfaces(_cli: &Cli, args: &MonitorArgs) -> Result<()> {
+pub fn monitor_interfaces(cli: &Cli, args: &MonitorArgs) -> Result<()> {
     // Settings
     let mut sort = args.sort;
     let target_iface = args.iface.clone(); // Option<String>
-    let tick = Duration::from_secs(args.interval.max(1));
+    // Global --default narrows to the default interface unless --iface is given
+    let only_default = cli.default;
+    let name_regex = compile_name_regex(args.name_regex.as_deref())?;
+    let name_lists = NameLists::load(args.include_file.as_deref(), args.exclude_file.as_deref())?;
+    let tick = args.interval.max(MIN_INTERVAL);
+    // Number of past samples kept per interface for rate averaging (--avg-window 1 = per tick)
+    let window_ticks = window_ticks(args.avg_window, tick);
+    let use_color = cli.use_color();
+    // --auto-unit takes precedence over --unit
+    let mut auto_unit = args.auto_unit;
+    let mut unit = if args.auto_unit {
+        Unit::Bytes
+    } else {
+        args.unit
+    };
+    let deadline = (args.duration > 0).then(|| Instant::now() + Duration::from_secs(args.duration));
+
+    if args.metric == Metric::Packets {
+        // Packet counters come from sysfs
+        if !cfg!(target_os = "linux") {
+            anyhow::bail!("--metric packets needs packet counters, which are only read on Linux");
+        }
+        if !matches!(args.output_mode, MonitorOutput::Tui) {
+            anyhow::bail!(
+                "--metric only applies to the TUI; ndjson/influx records carry both byte and packet rates"
+            );
+        }
+        // Remote snapshots and replayed stats only carry byte counters
+        if args.ssh.is_some() || args.stats_source.is_some() {
+            anyhow::bail!("--metric packets is not supported with --ssh or --stats-source");
+        }
+        if args.plot.is_some() {
+            anyhow::bail!("--plot charts byte rates and cannot be combined with --metric packets");
+        }
+    }
+
+    if args.notify && cfg!(windows) {
+        anyhow::bail!("--notify is not supported on Windows");
+    }
+    let mut alerts = Alerts::new(args);
+
+    // --stats-source: interfaces and counters come from the replay, filtered as usual
+    let mut replay: Option<Replay> = match &args.stats_source {
+        Some(path) => {
+            let mut stats = StatsReplay::open(path)?;
+            let (name_regex, name_lists) = (name_regex.as_ref(), &name_lists);
+            Some(Box::new(move || {
+                Ok(filter_interfaces(
+                    replayed_interfaces(stats.next_tick()?),
+                    args,
+                    only_default,
+                    name_regex,
+                    name_lists,
+                ))
+            }))
+        }
+        None => None,
+    };
+
+    if !matches!(args.output_mode, MonitorOutput::Tui) {
+        if args.ssh.is_some() {
+            anyhow::bail!("--ssh is only supported with the TUI output mode");
+        }
+        let ifs = match replay {
+            Some(_) => Vec::new(),
+            None => select_interfaces(args, only_default, name_regex.as_ref(), &name_lists),
+        };
+        return stream_records(args, ifs, replay, tick, window_ticks, deadline, alerts);
+    }
 
     // Switch terminal to TUI mode
     enable_raw_mode()?;
@@ -100,26 +561,23 @@ pub fn monitor_interfaces(_cli: &Cli, args: &MonitorArgs) -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
     terminal.clear()?;
 
-    let mut ifs = collect_all_interfaces();
-    // Collect (target IF only or all)
-    if let Some(ref name) = target_iface {
-        ifs.retain(|it| &it.name == name);
-    }
+    // --ssh: interfaces come from a remote snapshot refreshed every tick
+    let remote = args.ssh.as_deref();
+    let fetcher = remote.map(|target| RemoteFetcher::spawn(target.to_string(), tick));
+    let mut remote_snapshot: Option<Snapshot> = None;
+    let mut ssh_error: Option<String> = None;
 
-    let max_name_len = get_max_if_name_len(&ifs);
+    let mut ifs = if remote.is_some() || replay.is_some() {
+        Vec::new()
+    } else {
+        select_interfaces(args, only_default, name_regex.as_ref(), &name_lists)
+    };
 
-    let mut prev: HashMap<String, StatPoint> = HashMap::new();
+    let mut history: HashMap<String, VecDeque<StatPoint>> = HashMap::new();
     for itf in &mut ifs {
         let _ = itf.update_stats();
-        if let Some(st) = &itf.stats {
-            prev.insert(
-                itf.name.clone(),
-                StatPoint {
-                    rx_bytes: st.rx_bytes,
-                    tx_bytes: st.tx_bytes,
-                    ts: Instant::now(),
-                },
-            );
+        if let Some(p) = StatPoint::from_iface(itf) {
+            history.insert(itf.name.clone(), VecDeque::from([p]));
         }
     }
     let mut rows_cache: Vec<RowData> = Vec::new();
@@ -127,128 +585,313 @@ pub fn monitor_interfaces(_cli: &Cli, args: &MonitorArgs) -> Result<()> {
     let mut selected: usize = 0;
     let mut popup_open = false;
     let mut popup_scroll: u16 = 0;
+    let mut help_open = false;
+    // Confirmation or error of the last `e` snapshot
+    let mut flash: Option<Flash> = None;
+    let mut saver = SnapshotSaver::default();
+    // Gateway reachability for the interface in the detail popup (local only)
+    let mut gateway_prober: Option<GatewayProber> = None;
+    // Lookups for the interface in the detail popup, refreshed every tick
+    let mut popup_lookups: Option<PopupLookups> = None;
+
+    // Rate history for --plot: interface -> (seconds since start, bytes/s), last PLOT_WINDOW only
+    let started = Instant::now();
+    let mut plot_history: BTreeMap<String, VecDeque<(f64, f64)>> = BTreeMap::new();
 
     // Main loop
     let res = (|| -> Result<()> {
         loop {
             // Calculate remaining time until next tick
             let now = Instant::now();
-            let remain = if now >= next_tick {
+            if deadline.is_some_and(|d| now >= d) {
+                return Ok(());
+            }
+            let mut remain = if now >= next_tick {
                 Duration::from_millis(0)
             } else {
                 next_tick.saturating_duration_since(now)
             };
+            // Wake up in time to honor --duration
+            if let Some(d) = deadline {
+                remain = remain.min(d.saturating_duration_since(now));
+            }
 
             // Input processing (wait for the remaining time. If tick comes, exit with false)
-            if event::poll(remain)? {
-                if let Event::Key(key) = event::read()? {
-                    if key.kind == KeyEventKind::Press {
-                        match key.code {
-                            KeyCode::Char('q') => return Ok(()),
-                            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                                return Ok(());
-                            }
-                            KeyCode::Char('o') => sort = sort.cycle(),
-                            KeyCode::Char('r') => {
-                                ifs = collect_all_interfaces();
-                                if let Some(ref name) = target_iface {
-                                    ifs.retain(|it| &it.name == name);
-                                }
-                                prev.clear();
-                            }
-                            KeyCode::Up | KeyCode::Char('w') if !popup_open => {
-                                if selected > 0 {
-                                    selected -= 1;
-                                }
-                            }
-                            KeyCode::Down | KeyCode::Char('s') if !popup_open => {
-                                if selected + 1 < rows_cache.len() {
-                                    selected += 1;
-                                }
-                            }
-                            KeyCode::Up | KeyCode::Char('w') if popup_open => {
-                                popup_scroll = popup_scroll.saturating_sub(1);
-                            }
-                            KeyCode::Down | KeyCode::Char('s') if popup_open => {
-                                popup_scroll = popup_scroll.saturating_add(1);
+            let ev = if event::poll(remain)? {
+                Some(event::read()?)
+            } else {
+                None
+            };
+            // Resize: repaint everything at the new size instead of diffing stale cells
+            if let Some(Event::Resize(_, _)) = ev {
+                terminal.autoresize()?;
+                terminal.clear()?;
+                let shown = visible_rows(&rows_cache, args.top).len();
+                selected = selected.min(shown.saturating_sub(1));
+                // popup_scroll is clamped against the new popup height while drawing
+            }
+            if let Some(Event::Key(key)) = ev
+                && key.kind == KeyEventKind::Press
+            {
+                // The help popup swallows everything except quit/close keys
+                if help_open {
+                    match key.code {
+                        KeyCode::Char('q') => return Ok(()),
+                        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            return Ok(());
+                        }
+                        KeyCode::Char('?') | KeyCode::Esc => help_open = false,
+                        _ => {}
+                    }
+                } else {
+                    match key.code {
+                        KeyCode::Char('q') => return Ok(()),
+                        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            return Ok(());
+                        }
+                        KeyCode::Char('o') => {
+                            let sel_index = rows_cache.get(selected).map(|r| r.index);
+                            sort = sort.cycle();
+                            sort_rows(&mut rows_cache, sort);
+                            selected = follow_selection(&rows_cache, args.top, sel_index, selected);
+                        }
+                        KeyCode::Char(c @ '1'..='5') => {
+                            if let Some(key) = SortKey::from_digit(c) {
+                                let sel_index = rows_cache.get(selected).map(|r| r.index);
+                                sort = key;
+                                sort_rows(&mut rows_cache, sort);
+                                selected =
+                                    follow_selection(&rows_cache, args.top, sel_index, selected);
                             }
-                            KeyCode::Enter => {
-                                popup_open = true;
-                                popup_scroll = 0;
+                        }
+                        // Toggle bytes/bits (leaves --auto-unit mode)
+                        KeyCode::Char('b') | KeyCode::Char('B') => {
+                            unit = match unit {
+                                Unit::Bytes => Unit::Bits,
+                                Unit::Bits => Unit::Bytes,
+                            };
+                            auto_unit = false;
+                        }
+                        KeyCode::Char('e') => {
+                            // Remote: save what the last SSH fetch returned
+                            let text = if saver.start(remote_snapshot.clone()) {
+                                "saving snapshot…"
+                            } else {
+                                "still saving the last snapshot"
+                            };
+                            flash = Some(Flash::new(text.to_string(), false));
+                        }
+                        KeyCode::Char('r') => {
+                            if remote.is_none() && replay.is_none() {
+                                ifs = select_interfaces(
+                                    args,
+                                    only_default,
+                                    name_regex.as_ref(),
+                                    &name_lists,
+                                );
                             }
-                            KeyCode::Esc => {
-                                popup_open = false;
+                            // Remote: fetch a fresh snapshot right away
+                            if let Some(fetcher) = &fetcher {
+                                fetcher.retry_now();
                             }
-                            _ => {}
+                            next_tick = Instant::now();
+                            history.clear();
+                        }
+                        KeyCode::Up | KeyCode::Char('w') if !popup_open => {
+                            selected = selected.saturating_sub(1);
+                        }
+                        KeyCode::Down | KeyCode::Char('s')
+                            if !popup_open
+                                && selected + 1 < visible_rows(&rows_cache, args.top).len() =>
+                        {
+                            selected += 1;
+                        }
+                        KeyCode::Up | KeyCode::Char('w') if popup_open => {
+                            popup_scroll = popup_scroll.saturating_sub(1);
+                        }
+                        KeyCode::Down | KeyCode::Char('s') if popup_open => {
+                            popup_scroll = popup_scroll.saturating_add(1);
+                        }
+                        KeyCode::Enter => {
+                            popup_open = true;
+                            popup_scroll = 0;
+                            popup_lookups = None;
+                        }
+                        KeyCode::Esc => {
+                            popup_open = false;
+                            gateway_prober = None;
+                            popup_lookups = None;
                         }
+                        KeyCode::Char('?') => help_open = true,
+                        _ => {}
                     }
                 }
             }
 
+            if let Some(saved) = saver.finished() {
+                flash = Some(match saved {
+                    Ok(path) => Flash::new(format!("saved {}", path.display()), false),
+                    Err(e) => Flash::new(format!("snapshot failed: {:#}", e), true),
+                });
+            }
+
             // Tick processing
             if Instant::now() >= next_tick {
                 //next_tick = Instant::now() + tick;
                 next_tick += tick;
-                let tick_ts = Instant::now();
+                popup_lookups = None;
+                // Replayed samples carry the scheduled tick time, so their rates are exact
+                let mut tick_ts = if replay.is_some() {
+                    next_tick - tick
+                } else {
+                    Instant::now()
+                };
+                // Remote: only a snapshot that arrived since the last tick is sampled;
+                // otherwise the last rows stay up
+                let mut fresh = true;
+                if let Some(fetcher) = &fetcher {
+                    match fetcher.latest() {
+                        Some((fetched, Ok(snapshot))) => {
+                            ifs = filter_interfaces(
+                                snapshot.interfaces.clone(),
+                                args,
+                                only_default,
+                                name_regex.as_ref(),
+                                &name_lists,
+                            );
+                            remote_snapshot = Some(snapshot);
+                            ssh_error = None;
+                            // Rates follow when the counters were read, not our tick
+                            tick_ts = fetched;
+                        }
+                        // Keep the last rows and show a reconnect banner
+                        Some((_, Err(e))) => {
+                            ssh_error = Some(format!("{:#}", e));
+                            fresh = false;
+                        }
+                        // Still fetching
+                        None => fresh = false,
+                    }
+                }
+                if let Some(next) = replay.as_mut() {
+                    ifs = next()?;
+                }
                 let mut rows: Vec<RowData> = Vec::with_capacity(ifs.len());
-                for itf in &mut ifs {
-                    // Update stats
-                    let _ = itf.update_stats();
+                let sampled: &mut [netdev::Interface] = if fresh { &mut ifs } else { &mut [] };
+                for itf in sampled {
+                    // Update stats (remote/replayed stats arrive with the interfaces)
+                    let sample = if remote.is_some() || replay.is_some() {
+                        StatPoint::at_remote(itf, tick_ts)
+                    } else {
+                        let _ = itf.update_stats();
+                        StatPoint::at(itf, tick_ts)
+                    };
 
-                    if let Some(st) = itf.stats.as_ref() {
+                    if let Some(st) = itf.stats.as_ref()
+                        && let Some(nowp) = sample
+                    {
                         let key = itf.name.clone();
-                        // Current snapshot
-                        let nowp = StatPoint {
-                            rx_bytes: st.rx_bytes,
-                            tx_bytes: st.tx_bytes,
-                            ts: tick_ts,
-                        };
-                        // If there is a previous snapshot, calculate the difference; otherwise, use 0
-                        let rate = if let Some(prevp) = prev.get(&key) {
-                            let dt = nowp.ts.duration_since(prevp.ts).as_secs_f64().max(0.001);
-                            Rate {
-                                rx_per_s: (nowp.rx_bytes.saturating_sub(prevp.rx_bytes) as f64)
-                                    / dt,
-                                tx_per_s: (nowp.tx_bytes.saturating_sub(prevp.tx_bytes) as f64)
-                                    / dt,
-                            }
-                        } else {
-                            Rate {
-                                rx_per_s: 0.0,
-                                tx_per_s: 0.0,
-                            }
+                        // Average against the oldest sample in the window; otherwise, use 0
+                        let hist = history.entry(key).or_default();
+                        let rate = hist
+                            .front()
+                            .map(|prevp| Rate::between(prevp, &nowp))
+                            .unwrap_or_default();
+                        let (rx_delta, tx_delta) = hist.back().map_or((0, 0), |lastp| {
+                            (
+                                nowp.rx_bytes.saturating_sub(lastp.rx_bytes),
+                                nowp.tx_bytes.saturating_sub(lastp.tx_bytes),
+                            )
+                        });
+                        let (rx_pkts, tx_pkts) = hist.back().map_or((0, 0), |lastp| {
+                            (
+                                nowp.rx_packets.saturating_sub(lastp.rx_packets),
+                                nowp.tx_packets.saturating_sub(lastp.tx_packets),
+                            )
+                        });
+
+                        // Totals, rates and deltas follow --metric
+                        let (total_rx, total_tx, rx, tx, rx_moved, tx_moved) = match args.metric {
+                            Metric::Bytes => (
+                                st.rx_bytes,
+                                st.tx_bytes,
+                                rate.rx_per_s,
+                                rate.tx_per_s,
+                                rx_delta,
+                                tx_delta,
+                            ),
+                            Metric::Packets => (
+                                nowp.rx_packets,
+                                nowp.tx_packets,
+                                rate.rx_pkts_per_s,
+                                rate.tx_pkts_per_s,
+                                rx_pkts,
+                                tx_pkts,
+                            ),
                         };
 
-                        // Update prev for next time (only on tick)
-                        prev.insert(key.clone(), nowp);
+                        if let Some(text) = alerts.observe(&itf.name, &rate) {
+                            flash = Some(Flash::new(text, true));
+                        }
+
+                        // Update history for next time (only on tick)
+                        hist.push_back(nowp);
+                        while hist.len() > window_ticks {
+                            hist.pop_front();
+                        }
 
                         rows.push(RowData {
                             index: itf.index,
                             name: itf.name.clone(),
                             friendly_name: itf.friendly_name.clone(),
-                            total_rx: st.rx_bytes,
-                            total_tx: st.tx_bytes,
-                            total: st.rx_bytes + st.tx_bytes,
-                            rx: rate.rx_per_s,
-                            tx: rate.tx_per_s,
+                            total_rx,
+                            total_tx,
+                            total: total_rx + total_tx,
+                            loopback: itf.is_loopback(),
+                            rx,
+                            tx,
+                            rx_pps: rate.rx_pkts_per_s,
+                            tx_pps: rate.tx_pkts_per_s,
+                            rx_delta: rx_moved,
+                            tx_delta: tx_moved,
+                            rx_avg_frame: avg_frame(rx_delta, rx_pkts),
+                            tx_avg_frame: avg_frame(tx_delta, tx_pkts),
+                            no_stats: false,
+                        });
+                    } else {
+                        // Keep interfaces without counters visible
+                        rows.push(RowData {
+                            index: itf.index,
+                            name: itf.name.clone(),
+                            friendly_name: itf.friendly_name.clone(),
+                            loopback: itf.is_loopback(),
+                            no_stats: true,
+                            ..Default::default()
                         });
                     }
                 }
 
-                // Sort and replace cache (only on tick)
-                match sort {
-                    SortKey::Total => rows.sort_by(|a, b| b.total.cmp(&a.total)),
-                    SortKey::TotalRx => rows.sort_by(|a, b| b.total_rx.cmp(&a.total_rx)),
-                    SortKey::TotalTx => rows.sort_by(|a, b| b.total_tx.cmp(&a.total_tx)),
-                    SortKey::Rx => rows.sort_by(|a, b| b.rx.total_cmp(&a.rx)),
-                    SortKey::Tx => rows.sort_by(|a, b| b.tx.total_cmp(&a.tx)),
+                // Sort and replace cache (only on tick; kept until a new remote snapshot)
+                let sel_index = rows_cache.get(selected).map(|r| r.index);
+                if !fresh {
+                    rows = std::mem::take(&mut rows_cache);
                 }
-                rows_cache = rows;
-                if !rows_cache.is_empty() {
-                    if selected >= rows_cache.len() {
-                        selected = rows_cache.len() - 1;
+                sort_rows(&mut rows, sort);
+                if fresh && args.plot.is_some() {
+                    let t = tick_ts.duration_since(started).as_secs_f64();
+                    for r in rows.iter().filter(|r| !r.no_stats) {
+                        let points = plot_history.entry(r.name.clone()).or_default();
+                        points.push_back((t, r.rx + r.tx));
+                        while points
+                            .front()
+                            .is_some_and(|&(first, _)| t - first > PLOT_WINDOW.as_secs_f64())
+                        {
+                            points.pop_front();
+                        }
                     }
                 }
+                rows_cache = rows;
+                selected = follow_selection(&rows_cache, args.top, sel_index, selected);
             }
 
             // Draw using rows_cache at all times (maintain "previous value" when not tick)
@@ -263,30 +906,79 @@ pub fn monitor_interfaces(_cli: &Cli, args: &MonitorArgs) -> Result<()> {
                     .split(size);
 
                 // Header
-                let unit_label = match args.unit { Unit::Bytes => "bytes", Unit::Bits => "bits" };
+                let unit_label = if args.metric == Metric::Packets { "packets" } else if auto_unit { "auto" } else { match unit { Unit::Bytes => "bytes", Unit::Bits => "bits" } };
+                let avg_label = if window_ticks > 1 { format!(" — avg:{}s", args.avg_window) } else { String::new() };
                 let title = format!(
-                    "nifa monitor — sort:{:?} — unit:{} — interval:{}s {}",
-                    sort, unit_label, args.interval, target_iface.as_deref().unwrap_or("(all)")
+                    "nifa monitor{} — sort:{:?} — unit:{} — interval:{}{} {}",
+                    remote.map(|t| format!(" — ssh:{}", t)).unwrap_or_default(),
+                    sort, unit_label, humantime::format_duration(tick), avg_label, target_iface.as_deref().unwrap_or(if only_default { "(default)" } else { "(all)" })
+                );
+
+                let mut title_spans = vec![Span::raw(title)];
+                if let Some(msg) = flash.as_ref().filter(|m| Instant::now() < m.until) {
+                    title_spans.push(Span::styled(format!(" — {}", msg.text), msg.style(use_color)));
+                }
+
+                let max_name_len = get_max_if_name_len(&ifs);
+                // Optional ΔRX/ΔTX and RX avg/TX avg pairs
+                let extra_cols = 2 * (args.show_delta as u16 + args.show_avg_frame as u16);
+                // --layout: narrow windows drop Total RX/TX first, then Total
+                let (show_total, show_split) = table_columns(args.layout, chunks[0].width, max_name_len, extra_cols);
+
+                let mut header_cells = vec!["IFACE"];
+                if show_total {
+                    header_cells.push("Total");
+                }
+                if show_split {
+                    header_cells.extend(["Total RX", "Total TX"]);
+                }
+                header_cells.extend(["RX/s", "TX/s"]);
+                if args.show_delta {
+                    header_cells.extend(["ΔRX", "ΔTX"]);
+                }
+                if args.show_avg_frame {
+                    header_cells.extend(["RX avg", "TX avg"]);
+                }
+                header_cells.push("Load");
+                let header = Row::new(
+                    header_cells
+                        .into_iter()
+                        .map(|h| Span::styled(h, Style::default().add_modifier(Modifier::BOLD)))
+                        .collect::<Vec<_>>(),
                 );
 
-                let header = Row::new(vec![
-                    Span::styled("IFACE", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::styled("Total", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::styled("Total RX", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::styled("Total TX", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::styled("RX/s", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::styled("TX/s", Style::default().add_modifier(Modifier::BOLD)),
-                ]);
-
-                let rows_iter = rows_cache.iter().enumerate().map(|(i, r)| {
-                    let base = Row::new(vec![
-                        Span::raw(platform_if_name(r)),
-                        Span::raw(human_total(r.total, args.unit)),
-                        Span::raw(human_total(r.total_rx, args.unit)),
-                        Span::raw(human_total(r.total_tx, args.unit)),
-                        Span::raw(human_rate(r.rx, args.unit)),
-                        Span::raw(human_rate(r.tx, args.unit)),
-                    ]);
+                // Fixed-width value columns (RX/s, TX/s plus the optional ones)
+                let value_cols: u16 = 2 + show_total as u16 + 2 * show_split as u16 + extra_cols;
+                // The bar column takes whatever width is left after the fixed columns
+                let bar_width = chunks[0].width.saturating_sub(fixed_table_width(max_name_len, value_cols)) as usize;
+                // --top: only the busiest rows are drawn; TOTAL still covers all of them
+                let visible = visible_rows(&rows_cache, args.top);
+                let max_rate = visible.iter().map(|r| r.rx + r.tx).fold(0.0, f64::max);
+
+                let rows_iter = visible.iter().enumerate().map(|(i, r)| {
+                    let ratio = if max_rate > 0.0 { (r.rx + r.tx) / max_rate } else { 0.0 };
+                    // Interfaces without counters show "—" instead of zeros
+                    let value = |v: String| Span::raw(if r.no_stats { "—".to_string() } else { v });
+                    let mut cells = vec![Span::styled(platform_if_name(r), name_style(&r.name, use_color))];
+                    if show_total {
+                        cells.push(value(metric_total(r.total, unit, args.metric)));
+                    }
+                    if show_split {
+                        cells.push(value(metric_total(r.total_rx, unit, args.metric)));
+                        cells.push(value(metric_total(r.total_tx, unit, args.metric)));
+                    }
+                    cells.push(value(metric_rate(r.rx, r.rx_pps, unit, auto_unit, args.metric)));
+                    cells.push(value(metric_rate(r.tx, r.tx_pps, unit, auto_unit, args.metric)));
+                    if args.show_delta {
+                        cells.push(value(metric_total(r.rx_delta, unit, args.metric)));
+                        cells.push(value(metric_total(r.tx_delta, unit, args.metric)));
+                    }
+                    if args.show_avg_frame {
+                        cells.push(Span::raw(fmt_avg_frame(r.rx_avg_frame)));
+                        cells.push(Span::raw(fmt_avg_frame(r.tx_avg_frame)));
+                    }
+                    cells.push(Span::styled(rate_bar(ratio, bar_width), bar_style(ratio, use_color)));
+                    let base = Row::new(cells);
                     if i == selected {
                         base.style(Style::default().bg(ratatui::style::Color::DarkGray))
                     } else {
@@ -294,24 +986,56 @@ pub fn monitor_interfaces(_cli: &Cli, args: &MonitorArgs) -> Result<()> {
                     }
                 });
 
+                // Aggregate row (loopback excluded unless --include-loopback)
+                let total_row = (rows_cache.len() > 1).then(|| {
+                    let t = total_of(&rows_cache, args.totals.include_loopback);
+                    let mut cells = vec![Span::raw("TOTAL")];
+                    if show_total {
+                        cells.push(Span::raw(metric_total(t.total, unit, args.metric)));
+                    }
+                    if show_split {
+                        cells.push(Span::raw(metric_total(t.total_rx, unit, args.metric)));
+                        cells.push(Span::raw(metric_total(t.total_tx, unit, args.metric)));
+                    }
+                    cells.push(Span::raw(metric_rate(t.rx, t.rx_pps, unit, auto_unit, args.metric)));
+                    cells.push(Span::raw(metric_rate(t.tx, t.tx_pps, unit, auto_unit, args.metric)));
+                    if args.show_delta {
+                        cells.push(Span::raw(metric_total(t.rx_delta, unit, args.metric)));
+                        cells.push(Span::raw(metric_total(t.tx_delta, unit, args.metric)));
+                    }
+                    if args.show_avg_frame {
+                        cells.push(Span::raw(""));
+                        cells.push(Span::raw(""));
+                    }
+                    cells.push(Span::raw(""));
+                    Row::new(cells)
+                    .style(Style::default().add_modifier(Modifier::BOLD))
+                });
+                let rows_iter = rows_iter.chain(total_row);
+
                 // Table
-                let table = Table::new(rows_iter, [
-                        Constraint::Length(max_name_len),
-                        Constraint::Length(14),
-                        Constraint::Length(14),
-                        Constraint::Length(14),
-                        Constraint::Length(14),
-                        Constraint::Length(14),
-                    ])
+                let widths: Vec<Constraint> = std::iter::once(Constraint::Length(max_name_len))
+                    .chain((0..value_cols).map(|_| Constraint::Length(14)))
+                    .chain(std::iter::once(Constraint::Length(bar_width.max(MIN_BAR_WIDTH as usize) as u16)))
+                    .collect();
+                let table = Table::new(rows_iter, widths)
                     .header(header)
-                    .block(Block::default().borders(Borders::ALL).title(title))
+                    .block(Block::default().borders(Borders::ALL).title(Line::from(title_spans)))
                     .column_spacing(2);
 
                 f.render_widget(table, chunks[0]);
 
                 // Help
-                let help = "Press <q> to quit | <o> cycle sort | <r> rescan interfaces | ↑/↓/w/s select | Enter details | CTRL+C to exit";
-                let help_span = Span::styled(help, Style::default().fg(ratatui::style::Color::DarkGray));
+                let help_span = match (&ssh_error, remote) {
+                    (Some(err), Some(target)) => Span::styled(
+                        format!("SSH to {} failed, reconnecting every tick (<r> retry now): {}", target, err),
+                        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    ),
+                    _ => Span::styled(
+                        "Press <?> for help | <q> to quit | <o> cycle sort | ↑/↓/w/s select | Enter details",
+                        Style::default().fg(ratatui::style::Color::DarkGray),
+                    ),
+                };
                 let help_row = Row::new(vec![help_span]);
                 let help_table = Table::new(
                     std::iter::once(help_row),
@@ -332,14 +1056,26 @@ pub fn monitor_interfaces(_cli: &Cli, args: &MonitorArgs) -> Result<()> {
                         f.render_widget(Clear, area);
 
                         let block = Block::default()
-                            .title(format!("Details: {} (Esc to close — ↑/↓/w/s scroll)", iface.name))
+                            .title(format!("Details: {} (Esc to close — ↑/↓/w/s scroll)", display_name(&iface.name)))
                             .borders(Borders::ALL)
                             .style(Style::default().bg(Color::Black));
 
                         let inner = block.inner(area);
 
                         // Detail text (tree string created by termtree)
-                        let detail_text = iface_to_text(iface);
+                        if remote.is_none()
+                            && gateway_prober.as_ref().is_none_or(|p| p.index != iface.index)
+                        {
+                            gateway_prober = GatewayProber::spawn(iface, tick);
+                        }
+                        let reach = gateway_prober.as_ref().map(GatewayProber::reach);
+                        if popup_lookups.as_ref().is_some_and(|p| p.index != iface.index) {
+                            popup_lookups = None;
+                        }
+                        let popup = popup_lookups.get_or_insert_with(|| {
+                            PopupLookups::collect(iface, remote_snapshot.as_ref())
+                        });
+                        let detail_text = iface_to_text(iface, remote_snapshot.as_ref(), popup, &cli.hide, reach);
 
                         // Estimate content height (based on line breaks)
                         let content_lines = detail_text.lines().count() as u16;
@@ -359,6 +1095,31 @@ pub fn monitor_interfaces(_cli: &Cli, args: &MonitorArgs) -> Result<()> {
                     }
                 }
 
+                // Help popup
+                if help_open {
+                    let area = centered_rect(60, 60, size);
+                    f.render_widget(Clear, area);
+                    let key_width = KEYBINDINGS.iter().map(|(k, _)| k.chars().count()).max().unwrap_or(0);
+                    let lines: Vec<Line> = KEYBINDINGS
+                        .iter()
+                        .map(|(k, desc)| Line::from(vec![
+                            Span::styled(
+                                format!("{:<width$}  ", k, width = key_width),
+                                Style::default().add_modifier(Modifier::BOLD),
+                            ),
+                            Span::raw(*desc),
+                        ]))
+                        .collect();
+                    let block = Block::default()
+                        .title("Keybindings (? or Esc to close)")
+                        .borders(Borders::ALL)
+                        .style(Style::default().bg(Color::Black));
+                    let paragraph = Paragraph::new(Text::from(lines))
+                        .block(block)
+                        .wrap(Wrap { trim: false });
+                    f.render_widget(paragraph, area);
+                }
+
             })?;
         }
     })();
@@ -369,13 +1130,70 @@ pub fn monitor_interfaces(_cli: &Cli, args: &MonitorArgs) -> Result<()> {
     execute!(stdout, LeaveAlternateScreen, DisableMouseCapture)?;
     terminal.show_cursor()?;
 
+    if res.is_ok()
+        && let Some(path) = &args.plot
+    {
+        let series: Vec<RateSeries> = plot_history
+            .into_iter()
+            .map(|(name, points)| RateSeries {
+                name: display_name(&name),
+                points: points.into(),
+            })
+            .collect();
+        render_rate_png(path, &series, unit)?;
+        println!("Plot written to {}", path.display());
+    }
+
     // Return result of main loop
     res
 }
 
+/// Narrowest load bar worth drawing
+const MIN_BAR_WIDTH: u16 = 4;
+
+/// Width taken by borders, the name column and `value_cols` 14-wide columns
+/// (2 spaces between columns)
+fn fixed_table_width(name_len: u16, value_cols: u16) -> u16 {
+    2 + name_len + 14 * value_cols + 2 * (value_cols + 1)
+}
+
+/// Ticks covered by an --avg-window of `secs` seconds (1 = per-tick rate),
+/// capped at `MAX_WINDOW_TICKS`
+fn window_ticks(secs: u64, tick: Duration) -> usize {
+    if secs <= 1 {
+        return 1;
+    }
+    let ticks = Duration::from_secs(secs)
+        .as_nanos()
+        .div_ceil(tick.as_nanos().max(1));
+    ticks.min(MAX_WINDOW_TICKS as u128) as usize
+}
+
+/// Whether to show the Total and the Total RX/TX columns, as (total, split)
+fn table_columns(
+    layout: MonitorLayout,
+    width: u16,
+    name_len: u16,
+    extra_cols: u16,
+) -> (bool, bool) {
+    let fits = |value_cols: u16| fixed_table_width(name_len, value_cols) + MIN_BAR_WIDTH <= width;
+    match layout {
+        MonitorLayout::Wide => (true, true),
+        MonitorLayout::Narrow => (false, false),
+        MonitorLayout::Auto if fits(5 + extra_cols) => (true, true),
+        MonitorLayout::Auto if fits(3 + extra_cols) => (true, false),
+        MonitorLayout::Auto => (false, false),
+    }
+}
+
 /// Get the maximum interface name length for table column width
 /// On Windows, consider friendly_name if available
 fn get_max_if_name_len(ifs: &[netdev::Interface]) -> u16 {
+    let alias_len = ifs
+        .iter()
+        .filter_map(|it| alias_of(&it.name).map(|a| a.len() + it.name.len() + 3))
+        .max()
+        .unwrap_or(0);
     let max_len: usize = if cfg!(windows) {
         ifs.iter()
             .map(|it| {
@@ -390,26 +1208,29 @@ fn get_max_if_name_len(ifs: &[netdev::Interface]) -> u16 {
     } else {
         ifs.iter().map(|it| it.name.len()).max().unwrap_or(0)
     };
-    (max_len as u16).max(5)
+    (max_len.max(alias_len) as u16).max(5)
 }
 
 /// Platform-specific interface name specification
+/// Configured alias: `alias (name)`
 /// Linux/Unix: use `name` as-is
 /// Windows: use `friendly_name` if available; otherwise, use `name`
-fn platform_if_name(row: &RowData) -> &str {
-    if cfg!(windows) {
+fn platform_if_name(row: &RowData) -> String {
+    if alias_of(&row.name).is_some() {
+        display_name(&row.name)
+    } else if cfg!(windows) {
         if let Some(friendly_name) = &row.friendly_name {
-            friendly_name
+            friendly_name.clone()
         } else {
-            &row.name
+            row.name.clone()
         }
     } else {
-        &row.name
+        row.name.clone()
     }
 }
 
 // Total (Bytes or Bits)
-fn human_total(v_bytes: u64, unit: Unit) -> String {
+pub fn human_total(v_bytes: u64, unit: Unit) -> String {
     match unit {
         Unit::Bytes => format_size(v_bytes, BINARY),
         Unit::Bits => {
@@ -427,8 +1248,355 @@ fn human_total(v_bytes: u64, unit: Unit) -> String {
     }
 }
 
+/// Average frame size in bytes, None when no packets moved
+fn avg_frame(bytes: u64, packets: u64) -> Option<f64> {
+    (packets > 0).then(|| bytes as f64 / packets as f64)
+}
+
+fn fmt_avg_frame(avg: Option<f64>) -> String {
+    avg.map_or_else(|| "—".to_string(), |b| format!("{:.0} B", b))
+}
+
+/// Rows displayed in the table: the first `top` after sorting (0 = all)
+fn visible_rows(rows: &[RowData], top: usize) -> &[RowData] {
+    if top == 0 {
+        rows
+    } else {
+        &rows[..rows.len().min(top)]
+    }
+}
+
+/// Position of interface `index` among the visible rows, so the selection stays
+/// on it across re-sorts; `selected` clamped to the visible rows when it is gone
+fn follow_selection(rows: &[RowData], top: usize, index: Option<u32>, selected: usize) -> usize {
+    let shown = visible_rows(rows, top);
+    index
+        .and_then(|index| shown.iter().position(|r| r.index == index))
+        .unwrap_or_else(|| selected.min(shown.len().saturating_sub(1)))
+}
+
+/// Sum of all rows; loopback rows only count with `include_loopback`
+fn total_of(rows: &[RowData], include_loopback: bool) -> RowData {
+    let mut t = RowData {
+        name: "TOTAL".into(),
+        ..Default::default()
+    };
+    for r in rows.iter().filter(|r| include_loopback || !r.loopback) {
+        t.total_rx = t.total_rx.saturating_add(r.total_rx);
+        t.total_tx = t.total_tx.saturating_add(r.total_tx);
+        t.total = t.total.saturating_add(r.total);
+        t.rx += r.rx;
+        t.tx += r.tx;
+        t.rx_pps += r.rx_pps;
+        t.tx_pps += r.tx_pps;
+        t.rx_delta = t.rx_delta.saturating_add(r.rx_delta);
+        t.tx_delta = t.tx_delta.saturating_add(r.tx_delta);
+    }
+    t
+}
+
+/// Interfaces selected by --iface / --default / --name-regex / --exclude-name
+fn select_interfaces(
+    args: &MonitorArgs,
+    only_default: bool,
+    name_regex: Option<&Regex>,
+    name_lists: &NameLists,
+) -> Vec<netdev::Interface> {
+    filter_interfaces(
+        collect_all_interfaces(),
+        args,
+        only_default,
+        name_regex,
+        name_lists,
+    )
+}
+
+fn filter_interfaces(
+    mut ifs: Vec<netdev::Interface>,
+    args: &MonitorArgs,
+    only_default: bool,
+    name_regex: Option<&Regex>,
+    name_lists: &NameLists,
+) -> Vec<netdev::Interface> {
+    if let Some(name) = &args.iface {
+        ifs.retain(|it| &it.name == name);
+    } else if only_default {
+        ifs.retain(|it| it.default);
+    }
+    if let Some(re) = name_regex {
+        ifs.retain(|it| re.is_match(&it.name));
+    }
+    ifs.retain(|it| !is_excluded(&it.name, &args.exclude_name));
+    ifs.retain(|it| name_lists.allows(&it.name));
+    ifs
+}
+
+/// Traffic level of an ndjson record, from --idle-threshold / --high-threshold
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum RateLevel {
+    Idle,
+    Normal,
+    High,
+}
+
+impl RateLevel {
+    fn classify(bits_per_s: f64, idle: u64, high: u64) -> Self {
+        if bits_per_s < idle as f64 {
+            RateLevel::Idle
+        } else if bits_per_s >= high as f64 {
+            RateLevel::High
+        } else {
+            RateLevel::Normal
+        }
+    }
+}
+
+/// One line of `--output-mode ndjson`
+#[derive(Debug, Serialize)]
+struct NdjsonRecord<'a> {
+    ts: String,
+    iface: &'a str,
+    rx_bytes: u64,
+    tx_bytes: u64,
+    /// Bits per second
+    rx_bps: f64,
+    tx_bps: f64,
+    /// Packets per second; omitted where packet counters are unavailable
+    /// (non-Linux, `--stats-source`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rx_pps: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tx_pps: Option<f64>,
+    level: RateLevel,
+}
+
+/// InfluxDB line for one record: `nifa_iface,iface=<tag> field=value,... <ts_ns>`
+fn influx_line(record: &NdjsonRecord, ts_ns: u128) -> String {
+    let mut fields = format!(
+        "rx_bytes={}i,tx_bytes={}i,rx_bps={},tx_bps={}",
+        record.rx_bytes, record.tx_bytes, record.rx_bps, record.tx_bps
+    );
+    if let (Some(rx), Some(tx)) = (record.rx_pps, record.tx_pps) {
+        fields.push_str(&format!(",rx_pps={},tx_pps={}", rx, tx));
+    }
+    format!(
+        "nifa_iface,iface={} {} {}",
+        escape_influx_tag(record.iface),
+        fields,
+        ts_ns
+    )
+}
+
+/// Escape commas, equals signs and spaces in a line-protocol tag value
+fn escape_influx_tag(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, ',' | '=' | ' ') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Per-tick interface list with replayed counters (`--stats-source`)
+type Replay<'a> = Box<dyn FnMut() -> Result<Vec<netdev::Interface>> + 'a>;
+
+/// Placeholder interfaces carrying the replayed byte counters
+fn replayed_interfaces(counters: &[(String, u64, u64)]) -> Vec<netdev::Interface> {
+    counters
+        .iter()
+        .enumerate()
+        .map(|(i, (name, rx, tx))| {
+            let mut iface = netdev::Interface::dummy();
+            iface.index = i as u32 + 1;
+            iface.name = name.clone();
+            iface.stats = Some(netdev::stats::InterfaceStats {
+                rx_bytes: *rx,
+                tx_bytes: *tx,
+                timestamp: None,
+            });
+            iface
+        })
+        .collect()
+}
+
+/// Print one record per interface and tick to stdout (ndjson or influx)
+fn stream_records(
+    args: &MonitorArgs,
+    mut ifs: Vec<netdev::Interface>,
+    mut replay: Option<Replay>,
+    tick: Duration,
+    window_ticks: usize,
+    deadline: Option<Instant>,
+    mut alerts: Alerts,
+) -> Result<()> {
+    let mut history: HashMap<String, VecDeque<StatPoint>> = HashMap::new();
+    for itf in &mut ifs {
+        let _ = itf.update_stats();
+        if let Some(p) = StatPoint::from_iface(itf) {
+            history.insert(itf.name.clone(), VecDeque::from([p]));
+        }
+    }
+    // Replayed stats carry bytes only, and packet counters are only read on Linux
+    let has_packets = cfg!(target_os = "linux") && replay.is_none();
+    let mut stdout = io::stdout().lock();
+    let mut next_tick = Instant::now() + tick;
+    loop {
+        let now = Instant::now();
+        let wake = deadline.map_or(next_tick, |d| d.min(next_tick));
+        std::thread::sleep(wake.saturating_duration_since(now));
+        if deadline.is_some_and(|d| Instant::now() >= d) {
+            return Ok(());
+        }
+        next_tick += tick;
+
+        // Replayed samples carry the scheduled tick time, so their rates are exact
+        let tick_ts = if replay.is_some() {
+            next_tick - tick
+        } else {
+            Instant::now()
+        };
+        let wall = SystemTime::now();
+        let ts = humantime::format_rfc3339_seconds(wall).to_string();
+        let ts_ns = wall
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos());
+        if let Some(next) = replay.as_mut() {
+            ifs = next()?;
+        }
+        for itf in &mut ifs {
+            let sample = if replay.is_some() {
+                StatPoint::at_remote(itf, tick_ts)
+            } else {
+                let _ = itf.update_stats();
+                StatPoint::at(itf, tick_ts)
+            };
+            let Some(nowp) = sample else {
+                continue;
+            };
+            let hist = history.entry(itf.name.clone()).or_default();
+            let rate = hist
+                .front()
+                .map(|prevp| Rate::between(prevp, &nowp))
+                .unwrap_or_default();
+            hist.push_back(nowp.clone());
+            while hist.len() > window_ticks {
+                hist.pop_front();
+            }
+            // The record's level already says "high"; only the bell/notification fire here
+            let _ = alerts.observe(&itf.name, &rate);
+            let (rx_bps, tx_bps) = (rate.rx_per_s * 8.0, rate.tx_per_s * 8.0);
+            let record = NdjsonRecord {
+                ts: ts.clone(),
+                iface: &itf.name,
+                rx_bytes: nowp.rx_bytes,
+                tx_bytes: nowp.tx_bytes,
+                rx_bps,
+                tx_bps,
+                rx_pps: has_packets.then_some(rate.rx_pkts_per_s),
+                tx_pps: has_packets.then_some(rate.tx_pkts_per_s),
+                level: RateLevel::classify(
+                    rx_bps + tx_bps,
+                    args.idle_threshold,
+                    args.high_threshold,
+                ),
+            };
+            match args.output_mode {
+                MonitorOutput::Influx => writeln!(stdout, "{}", influx_line(&record, ts_ns))?,
+                _ => writeln!(stdout, "{}", serde_json::to_string(&record)?)?,
+            }
+        }
+        stdout.flush()?;
+    }
+}
+
+fn sort_rows(rows: &mut [RowData], sort: SortKey) {
+    match sort {
+        SortKey::Total => rows.sort_by_key(|r| std::cmp::Reverse(r.total)),
+        SortKey::TotalRx => rows.sort_by_key(|r| std::cmp::Reverse(r.total_rx)),
+        SortKey::TotalTx => rows.sort_by_key(|r| std::cmp::Reverse(r.total_tx)),
+        SortKey::Rx => rows.sort_by(|a, b| b.rx.total_cmp(&a.rx)),
+        SortKey::Tx => rows.sort_by(|a, b| b.tx.total_cmp(&a.tx)),
+    }
+}
+
+// In auto mode, fall back to packets/s when bytes are flat but packets move
+fn display_rate(bytes_per_s: f64, pkts_per_s: f64, unit: Unit, auto: bool) -> String {
+    if auto && bytes_per_s == 0.0 && pkts_per_s > 0.0 {
+        format!("{:.0} pkt/s", pkts_per_s)
+    } else {
+        human_rate(bytes_per_s, unit)
+    }
+}
+
+/// Total column value: humanized bytes/bits, or a plain packet count
+fn metric_total(v: u64, unit: Unit, metric: Metric) -> String {
+    match metric {
+        Metric::Bytes => human_total(v, unit),
+        Metric::Packets => format!("{} pkt", v),
+    }
+}
+
+/// Rate column value; in packets mode `v` already is packets/s
+fn metric_rate(v: f64, pkts_per_s: f64, unit: Unit, auto: bool, metric: Metric) -> String {
+    match metric {
+        Metric::Bytes => display_rate(v, pkts_per_s, unit, auto),
+        Metric::Packets => format!("{:.0} pkt/s", v),
+    }
+}
+
+// Unicode block bar for a 0.0..=1.0 ratio, with eighth-block resolution
+fn rate_bar(ratio: f64, width: usize) -> String {
+    const PARTIAL: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+    let eighths = (ratio.clamp(0.0, 1.0) * (width * 8) as f64).round() as usize;
+    let (full, rem) = (eighths / 8, eighths % 8);
+    let mut bar = "█".repeat(full);
+    if rem > 0 {
+        bar.push(PARTIAL[rem]);
+    }
+    bar
+}
+
+// Green/yellow/red depending on the share of the busiest interface
+fn bar_style(ratio: f64, use_color: bool) -> Style {
+    if !use_color {
+        return Style::default();
+    }
+    let color = if ratio >= 0.8 {
+        Color::Red
+    } else if ratio >= 0.5 {
+        Color::Yellow
+    } else {
+        Color::Green
+    };
+    Style::default().fg(color)
+}
+
+/// Colors for interface names: no red/yellow/green (used by the load bar),
+/// and readable on both dark and light backgrounds
+const NAME_PALETTE: [Color; 5] = [
+    Color::Cyan,
+    Color::Blue,
+    Color::Magenta,
+    Color::LightBlue,
+    Color::LightMagenta,
+];
+
+// Stable per-interface color (FNV-1a hash of the name)
+fn name_style(name: &str, use_color: bool) -> Style {
+    if !use_color {
+        return Style::default();
+    }
+    let hash = name.bytes().fold(0x811c_9dc5_u32, |h, b| {
+        (h ^ b as u32).wrapping_mul(0x0100_0193)
+    });
+    Style::default().fg(NAME_PALETTE[hash as usize % NAME_PALETTE.len()])
+}
+
 // Rate (Bytes/s or Bits/s)
-fn human_rate(v: f64, unit: Unit) -> String {
+pub fn human_rate(v: f64, unit: Unit) -> String {
     match unit {
         Unit::Bytes => {
             if v < 1000.0 {
@@ -473,11 +1641,72 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     area[1]
 }
 
-fn iface_to_text(iface: &netdev::Interface) -> String {
-    let host = crate::collector::sys::hostname();
+// Cap on neighbor entries shown in the detail popup
+const MAX_POPUP_NEIGHBORS: usize = 20;
+
+/// External-tool data for the detail popup of one interface, gathered once per tick
+/// instead of on every frame
+struct PopupLookups {
+    index: u32,
+    lookups: IfaceLookups,
+    /// ARP/NDP entries learned on the interface (empty for remote snapshots)
+    neighbors: Vec<NeighborEntry>,
+}
+
+impl PopupLookups {
+    /// A remote snapshot supplies the IPv6 lifetimes; its gateway MAC is whatever
+    /// netdev reported there, since the local NDP cache says nothing about that host
+    fn collect(iface: &netdev::Interface, remote: Option<&Snapshot>) -> Self {
+        let (lookups, neighbors) = match remote {
+            Some(snapshot) => (
+                IfaceLookups {
+                    ipv6_lifetimes: snapshot.ipv6_lifetimes.clone(),
+                    ..Default::default()
+                },
+                Vec::new(),
+            ),
+            None => (
+                IfaceLookups {
+                    ipv6_lifetimes: crate::collector::addr::collect_ipv6_lifetimes(),
+                    gateway_macs: crate::collector::iface::gateway_macs_from_ndp(
+                        std::slice::from_ref(iface),
+                    ),
+                    kernel_virtual: crate::collector::iface::kernel_virtual_names(
+                        std::slice::from_ref(iface),
+                    ),
+                    ..Default::default()
+                },
+                crate::collector::neighbor::neighbors_of(&iface.name),
+            ),
+        };
+        PopupLookups {
+            index: iface.index,
+            lookups,
+            neighbors,
+        }
+    }
+}
+
+/// Detail text for the popup. With a remote snapshot, the host comes from it.
+/// Sections in `hide` (`--hide`) are left out. `reach` adds the live gateway
+/// reachability under the Gateway node.
+fn iface_to_text(
+    iface: &netdev::Interface,
+    remote: Option<&Snapshot>,
+    popup: &PopupLookups,
+    hide: &[Section],
+    reach: Option<GatewayReach>,
+) -> String {
+    let shows = |section: Section| !hide.contains(&section);
+    let lookups = &popup.lookups;
+    let iface = &crate::collector::iface::with_sorted_addresses(iface);
+    let host = match remote {
+        Some(snapshot) => snapshot.sys.hostname.clone(),
+        None => crate::collector::sys::hostname(),
+    };
     let title = format!(
         "{}{} on {}",
-        iface.name,
+        display_name(&iface.name),
         if iface.default { " (default)" } else { "" },
         host
     );
@@ -493,19 +1722,23 @@ fn iface_to_text(iface: &netdev::Interface) -> String {
         root.push(Tree::new(format!("Description: {}", desc)));
     }
 
-    root.push(Tree::new(format!("Type: {:?}", iface.if_type)));
+    root.push(Tree::new(type_label(iface, lookups)));
     root.push(Tree::new(format!("State: {:?}", iface.oper_state)));
 
-    if let Some(mac) = &iface.mac_addr {
+    if shows(Section::Mac)
+        && let Some(mac) = &iface.mac_addr
+    {
         root.push(Tree::new(format!("MAC: {}", mac)));
     }
-    if let Some(mtu) = iface.mtu {
+    if shows(Section::Mtu)
+        && let Some(mtu) = iface.mtu
+    {
         root.push(Tree::new(format!("MTU: {}", mtu)));
     }
 
     // link speeds (humanized bps)
-    if iface.transmit_speed.is_some() || iface.receive_speed.is_some() {
-        let mut speed = Tree::new(tree_label("Link Speed"));
+    if shows(Section::Speed) && (iface.transmit_speed.is_some() || iface.receive_speed.is_some()) {
+        let mut speed = Tree::new(tree_label(link_speed_label(iface)));
         if let Some(tx) = iface.transmit_speed {
             speed.push(Tree::new(format!("TX: {}", fmt_bps(tx))));
         }
@@ -516,31 +1749,29 @@ fn iface_to_text(iface: &netdev::Interface) -> String {
     }
 
     // flags
-    root.push(Tree::new(format!("Flags: {}", fmt_flags(iface.flags))));
+    if shows(Section::Flags) {
+        root.push(Tree::new(format!("Flags: {}", fmt_flags(iface.flags))));
+    }
 
     // ---- Addresses ----
-    if !iface.ipv4.is_empty() {
+    if shows(Section::Ipv4) && !iface.ipv4.is_empty() {
         let mut ipv4_tree = Tree::new(tree_label("IPv4"));
         for net in &iface.ipv4 {
-            ipv4_tree.push(Tree::new(net.to_string()));
+            ipv4_tree.push(Tree::new(ipv4_label(net)));
         }
         root.push(ipv4_tree);
     }
 
-    if !iface.ipv6.is_empty() {
+    if shows(Section::Ipv6) && !iface.ipv6.is_empty() {
         let mut ipv6_tree = Tree::new(tree_label("IPv6"));
-        for (i, net) in iface.ipv6.iter().enumerate() {
-            let mut label = net.to_string();
-            if let Some(scope) = iface.ipv6_scope_ids.get(i) {
-                label.push_str(&format!(" (scope_id={})", scope));
-            }
-            ipv6_tree.push(Tree::new(label));
+        for i in 0..iface.ipv6.len() {
+            ipv6_tree.push(Tree::new(ipv6_label(iface, i, &lookups.ipv6_lifetimes)));
         }
         root.push(ipv6_tree);
     }
 
     // ---- DNS ----
-    if !iface.dns_servers.is_empty() {
+    if shows(Section::Dns) && !iface.dns_servers.is_empty() {
         let mut dns_tree = Tree::new(tree_label("DNS"));
         for dns in &iface.dns_servers {
             dns_tree.push(Tree::new(dns.to_string()));
@@ -549,28 +1780,57 @@ fn iface_to_text(iface: &netdev::Interface) -> String {
     }
 
     // ---- Gateway ----
-    if let Some(gw) = &iface.gateway {
+    if shows(Section::Gateway)
+        && let Some(gw) = &iface.gateway
+    {
         let mut gw_node = Tree::new(tree_label("Gateway"));
-        gw_node.push(Tree::new(format!("MAC: {}", gw.mac_addr)));
+        gw_node.push(Tree::new(format!(
+            "MAC: {}",
+            gateway_mac_label(iface, lookups)
+        )));
         if !gw.ipv4.is_empty() {
             let mut gw4 = Tree::new(tree_label("IPv4"));
             for ip in &gw.ipv4 {
                 gw4.push(Tree::new(ip.to_string()));
             }
+            if let Some(r) = reach.and_then(|r| r.v4) {
+                gw4.push(Tree::new(r.label()));
+            }
             gw_node.push(gw4);
         }
         if !gw.ipv6.is_empty() {
             let mut gw6 = Tree::new(tree_label("IPv6"));
             for ip in &gw.ipv6 {
-                gw6.push(Tree::new(ip.to_string()));
+                gw6.push(Tree::new(gateway_ipv6_label(iface, ip)));
+            }
+            if let Some(r) = reach.and_then(|r| r.v6) {
+                gw6.push(Tree::new(r.label()));
             }
             gw_node.push(gw6);
         }
         root.push(gw_node);
     }
 
+    // ---- Neighbors (ARP/NDP cache) ----
+    let neighbors = &popup.neighbors;
+    if !neighbors.is_empty() {
+        let mut nb_tree = Tree::new(tree_label("Neighbors"));
+        for n in neighbors.iter().take(MAX_POPUP_NEIGHBORS) {
+            nb_tree.push(Tree::new(format!("{} -> {}", n.ip, n.mac)));
+        }
+        if neighbors.len() > MAX_POPUP_NEIGHBORS {
+            nb_tree.push(Tree::new(format!(
+                "… {} more",
+                neighbors.len() - MAX_POPUP_NEIGHBORS
+            )));
+        }
+        root.push(nb_tree);
+    }
+
     // ---- Statistics (snapshot) ----
-    if let Some(st) = &iface.stats {
+    if shows(Section::Stats)
+        && let Some(st) = &iface.stats
+    {
         let mut stats_node = Tree::new(tree_label("Statistics (snapshot)"));
         stats_node.push(Tree::new(format!("RX bytes: {}", st.rx_bytes)));
         stats_node.push(Tree::new(format!("TX bytes: {}", st.tx_bytes)));
@@ -580,3 +1840,115 @@ fn iface_to_text(iface: &netdev::Interface) -> String {
     //println!("{}", root);
     format!("{}", root)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Value columns drawn for a `table_columns` result, as in the render loop
+    fn value_cols(show_total: bool, show_split: bool, extra_cols: u16) -> u16 {
+        2 + show_total as u16 + 2 * show_split as u16 + extra_cols
+    }
+
+    #[test]
+    fn auto_layout_leaves_room_for_the_bar() {
+        for extra_cols in [0, 2, 4] {
+            for name_len in [2, 8, 24] {
+                for width in (40..=240).step_by(4) {
+                    let (total, split) =
+                        table_columns(MonitorLayout::Auto, width, name_len, extra_cols);
+                    let minimum = fixed_table_width(name_len, value_cols(false, false, extra_cols));
+                    if minimum + MIN_BAR_WIDTH > width {
+                        // Nothing fits; Auto falls back to the fewest columns
+                        assert_eq!((total, split), (false, false));
+                        continue;
+                    }
+                    let used = fixed_table_width(name_len, value_cols(total, split, extra_cols));
+                    assert!(
+                        used + MIN_BAR_WIDTH <= width,
+                        "width {width}, name {name_len}, extra {extra_cols}: {used} used"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn auto_layout_narrow_and_wide_terminals() {
+        assert_eq!(table_columns(MonitorLayout::Auto, 40, 8, 0), (false, false));
+        assert_eq!(table_columns(MonitorLayout::Auto, 200, 8, 0), (true, true));
+        // In between, the RX/TX totals go first
+        assert_eq!(table_columns(MonitorLayout::Auto, 90, 8, 0), (true, false));
+        // Extra columns need a wider terminal for the same layout
+        assert_eq!(table_columns(MonitorLayout::Auto, 90, 8, 2), (false, false));
+        // Explicit layouts ignore the width
+        assert_eq!(table_columns(MonitorLayout::Wide, 40, 8, 0), (true, true));
+        assert_eq!(
+            table_columns(MonitorLayout::Narrow, 200, 8, 0),
+            (false, false)
+        );
+    }
+
+    #[test]
+    fn replayed_counters_give_exact_rates() {
+        let tick = Duration::from_secs(2);
+        let start = Instant::now();
+        let ticks = [
+            vec![
+                ("eth0".to_string(), 1_000, 500),
+                ("wlan0".to_string(), 10, 10),
+            ],
+            vec![
+                ("eth0".to_string(), 5_000, 2_500),
+                ("wlan0".to_string(), 4, 10),
+            ],
+        ];
+        let samples: Vec<Vec<StatPoint>> = ticks
+            .iter()
+            .enumerate()
+            .map(|(n, counters)| {
+                let ifs = replayed_interfaces(counters);
+                assert_eq!(ifs.iter().map(|i| i.index).collect::<Vec<_>>(), [1, 2]);
+                ifs.iter()
+                    .map(|itf| StatPoint::at_remote(itf, start + tick * n as u32).unwrap())
+                    .collect()
+            })
+            .collect();
+
+        let eth0 = Rate::between(&samples[0][0], &samples[1][0]);
+        assert_eq!((eth0.rx_per_s, eth0.tx_per_s), (2_000.0, 1_000.0));
+        assert_eq!((eth0.rx_pkts_per_s, eth0.tx_pkts_per_s), (0.0, 0.0));
+        // A counter that went backwards (reset) reads as idle, not as a huge rate
+        let wlan0 = Rate::between(&samples[0][1], &samples[1][1]);
+        assert_eq!((wlan0.rx_per_s, wlan0.tx_per_s), (0.0, 0.0));
+    }
+
+    #[test]
+    fn avg_window_ticks_round_up_and_stay_bounded() {
+        let tick = Duration::from_millis(300);
+        assert_eq!(window_ticks(1, tick), 1);
+        assert_eq!(window_ticks(3, tick), 10);
+        assert_eq!(window_ticks(1, Duration::from_secs(2)), 1);
+        assert_eq!(window_ticks(5, Duration::from_secs(2)), 3);
+        assert_eq!(window_ticks(u64::MAX, MIN_INTERVAL), MAX_WINDOW_TICKS);
+    }
+
+    #[test]
+    fn selection_follows_the_interface_across_resorts() {
+        let row = |index: u32, total: u64| RowData {
+            index,
+            total,
+            ..Default::default()
+        };
+        let mut rows = vec![row(1, 10), row(2, 30), row(3, 20)];
+        // Interface 3 is selected, then the rows are sorted by total
+        let selected = 2;
+        let sel_index = rows.get(selected).map(|r| r.index);
+        sort_rows(&mut rows, SortKey::Total);
+        assert_eq!(follow_selection(&rows, 0, sel_index, selected), 1);
+        // Pushed out by --top: clamp to the last visible row
+        assert_eq!(follow_selection(&rows, 1, Some(3), 2), 0);
+        // Gone: keep the position, clamped
+        assert_eq!(follow_selection(&rows[..2], 0, Some(9), 2), 1);
+    }
+}
@@ -1,18 +1,74 @@
-use crate::cli::Cli;
+use anyhow::Result;
+
+use crate::cli::{Cli, OsArgs};
+use crate::cmd::Timing;
+use crate::model::snapshot::{OsReport, Snapshot};
 
 /// Show system network stack details
-pub fn show_system_net_stack(cli: &Cli) {
-    let sys_info = crate::collector::sys::system_info();
-    let default_iface_opt = crate::collector::iface::get_default_interface();
-    match cli.format {
-        crate::cli::OutputFormat::Tree => {
-            crate::renderer::tree::print_system_with_default_iface(&sys_info, default_iface_opt)
-        }
-        crate::cli::OutputFormat::Json => {
-            crate::renderer::json::print_snapshot_json(&sys_info, default_iface_opt)
+pub async fn show_system_net_stack(cli: &Cli, args: &OsArgs) -> Result<()> {
+    let mut timing = Timing::start(cli);
+    let mut sys_info = crate::collector::sys::system_info();
+    // Only `os` shows forwarding; on Windows it costs two powershell runs
+    (sys_info.ipv4_forwarding, sys_info.ipv6_forwarding) = crate::collector::sys::ip_forwarding();
+    if args.sockets {
+        sys_info.sockets = Some(crate::collector::sys::socket_counts());
+    }
+    let interfaces = crate::collector::iface::collect_all_interfaces();
+    let totals = crate::collector::iface::total_traffic(&interfaces, args.totals.include_loopback);
+    let mut default_iface_opt = crate::collector::iface::get_default_interface();
+    if cli.sort_addresses
+        && let Some(iface) = default_iface_opt.as_mut()
+    {
+        crate::collector::iface::sort_addresses(iface);
+    }
+    let dhcp = default_iface_opt
+        .as_ref()
+        .map(crate::collector::dhcp::dhcp_info);
+    let mut doh = crate::collector::dns::detect_doh(default_iface_opt.as_ref());
+    if args.check_doh {
+        doh.probes = crate::collector::dns::probe_doh_endpoints().await;
+    }
+    // The JSON/YAML snapshot carries the lookups too
+    let lookups = crate::collector::IfaceLookups::collect(default_iface_opt.as_slice());
+    let os_info = args.verbose_os.then(os_info::get);
+    timing.collected(interfaces.len());
+    let opts = crate::renderer::tree::TreeOptions::from_cli(cli);
+    match (&cli.template, cli.format) {
+        (None, crate::cli::OutputFormat::Tree) => {
+            crate::renderer::tree::print_system_with_default_iface(
+                &sys_info,
+                default_iface_opt,
+                &lookups,
+                dhcp.as_ref(),
+                &totals,
+                args.unit,
+                &doh,
+                &opts,
+            );
+            if let Some(info) = &os_info {
+                crate::renderer::tree::print_os_info_tree(info, &opts);
+            }
         }
-        crate::cli::OutputFormat::Yaml => {
-            crate::renderer::yaml::print_snapshot_yaml(&sys_info, default_iface_opt)
+        (template, format) => {
+            let report = OsReport {
+                snapshot: Snapshot::new(
+                    sys_info,
+                    default_iface_opt.into_iter().collect(),
+                    &lookups,
+                ),
+                dhcp,
+                doh,
+                os_info,
+            };
+            match (template, format) {
+                (Some(_), _) => crate::cmd::export::print_with_template(cli, &report)?,
+                (None, crate::cli::OutputFormat::Json) => {
+                    crate::renderer::json::print_os_report_json(&report, cli.use_color())
+                }
+                _ => crate::renderer::yaml::print_os_report_yaml(&report),
+            }
         }
     }
+    timing.finish();
+    Ok(())
 }
@@ -1,10 +1,16 @@
 use anyhow::{Context, Result};
 use reqwest::Client;
-use std::time::Duration;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
 
 use crate::cli::{Cli, OutputFormat, PublicArgs};
-use crate::model::ipinfo::{CommonInfo, IpInfo, IpSide, PublicOut};
-use crate::renderer::tree::print_public_ip_tree;
+use crate::config::ProviderSpec;
+use crate::model::ipinfo::{CommonInfo, IpInfo, IpSide, PublicHistoryEntry, PublicOut};
+use crate::renderer::tree::{TreeOptions, print_public_ip_tree};
 
 const IPSTRUCT_URL: &str = "https://api.ipstruct.com/ip";
 const IPSTRUCT_V4_URL: &str = "https://ipv4.ipstruct.com/ip";
@@ -14,14 +20,35 @@ const IP_VERSION_6: &str = "v6";
 /// Show public IP information
 pub async fn show_public_ip_info(cli: &Cli, args: &PublicArgs) -> Result<()> {
     let client = Client::builder()
-        .timeout(Duration::from_secs(args.timeout.max(1)))
+        .timeout(args.timeout.max(Duration::from_millis(100)))
         .build()
         .context("build http client")?;
 
     let v4: Option<IpInfo>;
     let mut v6: Option<IpInfo> = None;
 
-    if args.ipv4 {
+    if let Some(name) = &args.provider {
+        let spec = crate::config::provider(name)
+            .with_context(|| format!("Provider '{}' not found in config file", name))?;
+        match fetch_provider(&client, spec).await? {
+            Some(info) if is_ipv6(&info) => {
+                v6 = Some(info);
+                v4 = None;
+            }
+            info => v4 = info,
+        }
+    } else if args.v6_only {
+        // The "any" endpoint answers over IPv6 when available
+        match fetch_ip(&client, IPSTRUCT_URL).await? {
+            Some(info) if is_ipv6(&info) => v6 = Some(info),
+            Some(info) => anyhow::bail!(
+                "Expected an IPv6 address but got {} (no IPv6 connectivity?)",
+                info.ip_addr
+            ),
+            None => {}
+        }
+        v4 = None;
+    } else if args.ipv4 || args.raw {
         v4 = fetch_ip(&client, IPSTRUCT_V4_URL).await?;
     } else {
         let (any_res, v4_res) = tokio::join!(
@@ -46,18 +73,135 @@ pub async fn show_public_ip_info(cli: &Cli, args: &PublicArgs) -> Result<()> {
         }
     }
 
-    let out = build_public_out(v4, v6);
+    // IPv6 availability as an exit status for scripts
+    if args.has_v6 {
+        if args.print {
+            println!("{}", v6.is_some());
+        }
+        return match v6 {
+            Some(_) => Ok(()),
+            None => Err(crate::cmd::ExitStatus(1).into()),
+        };
+    }
+
+    // Bare address for scripts, no renderer involved
+    if args.raw {
+        let (side, family) = if args.v6_only {
+            (v6, "IPv6")
+        } else {
+            (v4, "IPv4")
+        };
+        match side {
+            Some(info) => println!("{}", info.ip_addr),
+            None => anyhow::bail!("Could not determine public {} address", family),
+        }
+        return Ok(());
+    }
+
+    let mut out = build_public_out(v4, v6, !args.no_common);
+
+    let mut history_line = None;
+    if let Some(path) = &args.history
+        && (out.ipv4.is_some() || out.ipv6.is_some())
+    {
+        let (line, entry) = record_history(path, out)?;
+        out = entry.out;
+        history_line = Some(line);
+    }
 
     let default_iface_opt = crate::collector::iface::get_default_interface();
 
     match cli.format {
-        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&out)?),
+        OutputFormat::Json => crate::renderer::json::print_json(&out, cli.use_color())?,
         OutputFormat::Yaml => println!("{}", serde_yaml::to_string(&out)?),
-        _ => print_public_ip_tree(&out, default_iface_opt),
+        _ => {
+            // The public tree shows everything but the zone
+            let lookups = crate::collector::IfaceLookups {
+                search_domains: crate::collector::dns::collect_search_domains(),
+                ipv6_lifetimes: crate::collector::addr::collect_ipv6_lifetimes(),
+                gateway_macs: crate::collector::iface::gateway_macs_from_ndp(
+                    default_iface_opt.as_slice(),
+                ),
+                kernel_virtual: crate::collector::iface::kernel_virtual_names(
+                    default_iface_opt.as_slice(),
+                ),
+                ..Default::default()
+            };
+            print_public_ip_tree(
+                &out,
+                default_iface_opt,
+                &lookups,
+                &TreeOptions::from_cli(cli),
+            )
+        }
+    }
+    if let Some(line) = history_line {
+        match cli.format {
+            OutputFormat::Tree => println!("{}", line),
+            // Keep stdout parseable for JSON/YAML
+            _ => eprintln!("{}", line),
+        }
     }
     Ok(())
 }
 
+/// Append `out` to the history file and describe how it compares to the
+/// last recorded entry. A missing or empty file counts as the first record.
+fn record_history(path: &Path, out: PublicOut) -> Result<(String, PublicHistoryEntry)> {
+    let last = match fs::read_to_string(path) {
+        Ok(text) => match text.lines().rev().find(|l| !l.trim().is_empty()) {
+            Some(line) => Some(
+                serde_json::from_str::<PublicHistoryEntry>(line)
+                    .with_context(|| format!("Malformed last entry in {}", path.display()))?,
+            ),
+            None => None,
+        },
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => return Err(e).with_context(|| format!("read {}", path.display())),
+    };
+    let entry = PublicHistoryEntry {
+        ts: humantime::format_rfc3339_seconds(SystemTime::now()).to_string(),
+        out,
+    };
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("open {}", path.display()))?;
+    writeln!(file, "{}", serde_json::to_string(&entry)?)
+        .with_context(|| format!("write {}", path.display()))?;
+
+    let line = match &last {
+        None => format!("History: first record in {}", path.display()),
+        Some(prev) if entry.addresses_differ(prev) => {
+            format!(
+                "History: changed since {} ({})",
+                prev.ts,
+                describe_change(prev, &entry)
+            )
+        }
+        Some(prev) => format!("History: unchanged since {}", prev.ts),
+    };
+    Ok((line, entry))
+}
+
+/// `IPv4 a -> b, IPv6 c -> d` for the families whose address changed
+fn describe_change(prev: &PublicHistoryEntry, now: &PublicHistoryEntry) -> String {
+    let addr = |side: &Option<IpSide>| {
+        side.as_ref()
+            .map_or_else(|| "none".to_string(), |s| s.ip_addr.clone())
+    };
+    [
+        ("IPv4", addr(&prev.out.ipv4), addr(&now.out.ipv4)),
+        ("IPv6", addr(&prev.out.ipv6), addr(&now.out.ipv6)),
+    ]
+    .into_iter()
+    .filter(|(_, before, after)| before != after)
+    .map(|(family, before, after)| format!("{} {} -> {}", family, before, after))
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
 /// Fetch IP information from a given URL
 async fn fetch_ip(client: &Client, url: &str) -> Result<Option<IpInfo>> {
     let resp = client
@@ -72,11 +216,63 @@ async fn fetch_ip(client: &Client, url: &str) -> Result<Option<IpInfo>> {
     Ok(Some(info))
 }
 
+/// Fetch IP information from a custom provider, applying its key mapping
+async fn fetch_provider(client: &Client, spec: &ProviderSpec) -> Result<Option<IpInfo>> {
+    if spec.mapping.is_empty() {
+        return fetch_ip(client, &spec.url).await;
+    }
+    let resp = client
+        .get(&spec.url)
+        .send()
+        .await
+        .with_context(|| format!("GET {}", spec.url))?;
+    if !resp.status().is_success() {
+        anyhow::bail!("{} -> HTTP {}", spec.url, resp.status());
+    }
+    let value: Value = resp.json().await.context("parse json response")?;
+    Ok(Some(map_ip_info(&value, &spec.mapping)?))
+}
+
+/// Build `IpInfo` from an arbitrary JSON document via `field -> dotted.path` mapping
+fn map_ip_info(value: &Value, mapping: &BTreeMap<String, String>) -> Result<IpInfo> {
+    let get = |field: &str| -> String {
+        mapping
+            .get(field)
+            .and_then(|path| path.split('.').try_fold(value, |v, key| v.get(key)))
+            .map(|v| match v {
+                Value::String(s) => s.clone(),
+                Value::Null => String::new(),
+                other => other.to_string(),
+            })
+            .unwrap_or_default()
+    };
+    let ip_addr = get("ip_addr");
+    if ip_addr.is_empty() {
+        anyhow::bail!("Provider response has no value for mapped field 'ip_addr'");
+    }
+    let mut ip_version = get("ip_version");
+    if ip_version.is_empty() {
+        ip_version = if ip_addr.contains(':') { "v6" } else { "v4" }.to_string();
+    }
+    Ok(IpInfo {
+        ip_version,
+        ip_addr_dec: get("ip_addr_dec"),
+        ip_addr,
+        host_name: get("host_name"),
+        network: get("network"),
+        asn: get("asn"),
+        as_name: get("as_name"),
+        country_code: get("country_code"),
+        country_name: get("country_name"),
+    })
+}
+
 fn is_ipv6(info: &IpInfo) -> bool {
     info.ip_version == IP_VERSION_6 || info.ip_addr.contains(':')
 }
 
-fn build_public_out(v4: Option<IpInfo>, v6: Option<IpInfo>) -> PublicOut {
+/// Build the output; with `allow_common`, matching ASN/country are grouped into `common`
+fn build_public_out(v4: Option<IpInfo>, v6: Option<IpInfo>, allow_common: bool) -> PublicOut {
     // v4 or v6 is missing, cannot commonize
     if v4.is_none() || v6.is_none() {
         return PublicOut {
@@ -113,7 +309,7 @@ fn build_public_out(v4: Option<IpInfo>, v6: Option<IpInfo>) -> PublicOut {
     let same_country = v4i.country_name == v6i.country_name;
 
     // If all fields are the same, we can commonize
-    if same_asn && same_as_name && same_cc && same_country {
+    if allow_common && same_asn && same_as_name && same_cc && same_country {
         PublicOut {
             common: Some(CommonInfo {
                 asn: v4i.asn.clone(),
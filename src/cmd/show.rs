@@ -1,23 +1,145 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+
 use crate::cli::Cli;
 use crate::cli::ShowArgs;
+use crate::cmd::Timing;
+use crate::cmd::export;
+use crate::cmd::monitor::{Rate, StatPoint};
 use crate::collector;
 use crate::renderer;
 
 /// Show specified interface details
-pub fn show_interface(cli: &Cli, args: &ShowArgs) {
+pub fn show_interface(cli: &Cli, args: &ShowArgs) -> Result<()> {
+    if args.iface == "-" {
+        return show_interfaces_from_stdin(cli, args);
+    }
+    let mut timing = Timing::start(cli);
     match collector::iface::get_interface_by_name(&args.iface) {
-        Some(iface) => {
+        Some(mut iface) => {
+            if cli.sort_addresses {
+                collector::iface::sort_addresses(&mut iface);
+            }
+            // The rate sample only feeds the tree and counts as collection
+            let (rate, lookups) = match cli.format {
+                _ if cli.template.is_some() => (
+                    None,
+                    collector::IfaceLookups::collect(std::slice::from_ref(&iface)),
+                ),
+                crate::cli::OutputFormat::Tree => (
+                    sample_rates(std::slice::from_mut(&mut iface), args.sample_ms)
+                        .pop()
+                        .flatten(),
+                    collector::IfaceLookups::collect(std::slice::from_ref(&iface)),
+                ),
+                _ => (None, Default::default()),
+            };
+            let template = export::template_snapshot(cli, std::slice::from_ref(&iface), &lookups);
+            timing.collected(1);
             // Render output
-            match cli.format {
-                crate::cli::OutputFormat::Tree => {
-                    renderer::tree::print_interface_detail_tree(&iface)
+            match (&template, cli.format) {
+                (Some(snapshot), _) => export::print_with_template(cli, snapshot)?,
+                (None, crate::cli::OutputFormat::Tree) => {
+                    renderer::tree::print_interface_detail_tree(
+                        &iface,
+                        rate.as_ref(),
+                        &lookups,
+                        &renderer::tree::TreeOptions::from_cli(cli),
+                    )
+                }
+                (None, crate::cli::OutputFormat::Json) => {
+                    renderer::json::print_interface_json(&[iface], cli.use_color())
+                }
+                (None, crate::cli::OutputFormat::Yaml) => {
+                    renderer::yaml::print_interface_yaml(&[iface])
                 }
-                crate::cli::OutputFormat::Json => renderer::json::print_interface_json(&[iface]),
-                crate::cli::OutputFormat::Yaml => renderer::yaml::print_interface_yaml(&[iface]),
             }
         }
         None => {
-            tracing::error!("Interface '{}' not found", args.iface);
+            anyhow::bail!("Interface '{}' not found", args.iface);
+        }
+    }
+    timing.finish();
+    Ok(())
+}
+
+/// Read interface names from stdin (one per line) and show each in turn.
+/// Unknown names are reported on stderr and make the command exit with 1.
+fn show_interfaces_from_stdin(cli: &Cli, args: &ShowArgs) -> Result<()> {
+    let mut timing = Timing::start(cli);
+    let all = collector::iface::collect_all_interfaces();
+    let mut found = Vec::new();
+    let mut missing = 0;
+    for (n, line) in std::io::stdin().lines().enumerate() {
+        let line = line.context("Failed to read interface names from stdin")?;
+        let name = line.trim();
+        if name.is_empty() {
+            continue;
+        }
+        match all.iter().find(|i| i.name == name) {
+            Some(iface) => found.push(iface.clone()),
+            None => {
+                eprintln!("line {}: interface '{}' not found", n + 1, name);
+                missing += 1;
+            }
+        }
+    }
+    if cli.sort_addresses {
+        found.iter_mut().for_each(collector::iface::sort_addresses);
+    }
+    let (rates, lookups): (Vec<Option<Rate>>, _) = match cli.format {
+        _ if cli.template.is_some() => (Vec::new(), collector::IfaceLookups::collect(&found)),
+        crate::cli::OutputFormat::Tree => (
+            sample_rates(&mut found, args.sample_ms),
+            collector::IfaceLookups::collect(&found),
+        ),
+        _ => (Vec::new(), Default::default()),
+    };
+    let template = export::template_snapshot(cli, &found, &lookups);
+    timing.collected(found.len());
+
+    match (&template, cli.format) {
+        (Some(snapshot), _) => export::print_with_template(cli, snapshot)?,
+        (None, crate::cli::OutputFormat::Tree) => {
+            let opts = renderer::tree::TreeOptions::from_cli(cli);
+            for (iface, rate) in found.iter().zip(&rates) {
+                renderer::tree::print_interface_detail_tree(iface, rate.as_ref(), &lookups, &opts);
+            }
         }
+        (None, crate::cli::OutputFormat::Json) => {
+            renderer::json::print_interface_json(&found, cli.use_color())
+        }
+        (None, crate::cli::OutputFormat::Yaml) => renderer::yaml::print_interface_yaml(&found),
+    }
+    timing.finish();
+    if missing > 0 {
+        return Err(crate::cmd::ExitStatus(1).into());
+    }
+    Ok(())
+}
+
+/// Sample the stats of all `ifaces` twice `sample_ms` apart (one wait for all)
+/// and compute each rate, in order
+fn sample_rates(ifaces: &mut [netdev::Interface], sample_ms: u64) -> Vec<Option<Rate>> {
+    if sample_ms == 0 {
+        return vec![None; ifaces.len()];
     }
+    let first: Vec<Option<StatPoint>> = ifaces
+        .iter_mut()
+        .map(|iface| {
+            let _ = iface.update_stats();
+            StatPoint::from_iface(iface)
+        })
+        .collect();
+    std::thread::sleep(Duration::from_millis(sample_ms));
+    ifaces
+        .iter_mut()
+        .zip(first)
+        .map(|(iface, first)| {
+            let _ = iface.update_stats();
+            let second = StatPoint::from_iface(iface)?;
+            Some(Rate::between(&first?, &second))
+        })
+        .collect()
 }
@@ -0,0 +1,122 @@
+use std::net::IpAddr;
+
+use anyhow::{Context, Result};
+use netdev::Interface;
+
+use crate::cli::{Cli, OutputFormat, ValidateArgs};
+use crate::collector;
+use crate::model::check::{CheckReport, Finding};
+use crate::model::expectations::{Expectations, InterfaceExpectation};
+use crate::model::load_json_or_yaml;
+use crate::model::snapshot::Snapshot;
+use crate::renderer::tree::{TreeOptions, print_validate_tree};
+
+/// Compare live interfaces against an expectations file
+pub fn validate_interfaces(cli: &Cli, args: &ValidateArgs) -> Result<()> {
+    let expectations: Expectations = load_json_or_yaml(&args.file)
+        .with_context(|| format!("Invalid expectations file {}", args.file.display()))?;
+    let interfaces = match &args.snapshot {
+        Some(path) => Snapshot::from_path(path)?.interfaces,
+        None => collector::iface::collect_all_interfaces(),
+    };
+
+    let mut findings = Vec::new();
+    for (name, expected) in &expectations.interfaces {
+        match interfaces.iter().find(|i| &i.name == name) {
+            Some(iface) => findings.extend(validate_interface(iface, expected)),
+            None => findings.push(Finding::fail(
+                &format!("{}.present", name),
+                "interface not found",
+            )),
+        }
+    }
+
+    let report = CheckReport { findings };
+    match cli.format {
+        OutputFormat::Json => crate::renderer::json::print_json(&report, cli.use_color())?,
+        OutputFormat::Yaml => println!("{}", serde_yaml::to_string(&report)?),
+        OutputFormat::Tree => print_validate_tree(&report, &TreeOptions::from_cli(cli)),
+    }
+    if report.failures() > 0 {
+        return Err(crate::cmd::ExitStatus(1).into());
+    }
+    Ok(())
+}
+
+fn validate_interface(iface: &Interface, expected: &InterfaceExpectation) -> Vec<Finding> {
+    let name = &iface.name;
+    let mut findings = Vec::new();
+    let check = |rule: &str, pass: bool, message: String| {
+        let rule = format!("{}.{}", name, rule);
+        if pass {
+            Finding::ok(&rule, message)
+        } else {
+            Finding::fail(&rule, message)
+        }
+    };
+
+    if let Some(mtu) = expected.mtu {
+        findings.push(check(
+            "mtu",
+            iface.mtu == Some(mtu),
+            format!("expected {}, found {}", mtu, fmt_opt(iface.mtu)),
+        ));
+    }
+    if let Some(state) = &expected.state {
+        let actual = format!("{:?}", iface.oper_state).to_lowercase();
+        findings.push(check(
+            "state",
+            actual.eq_ignore_ascii_case(state),
+            format!("expected {}, found {}", state, actual),
+        ));
+    }
+    let v4: Vec<String> = iface.ipv4.iter().map(|n| n.to_string()).collect();
+    for want in &expected.ipv4 {
+        findings.push(check(
+            "ipv4",
+            has_addr(&v4, want),
+            format!("expected {} in [{}]", want, v4.join(", ")),
+        ));
+    }
+    let v6: Vec<String> = iface.ipv6.iter().map(|n| n.to_string()).collect();
+    for want in &expected.ipv6 {
+        findings.push(check(
+            "ipv6",
+            has_addr(&v6, want),
+            format!("expected {} in [{}]", want, v6.join(", ")),
+        ));
+    }
+    if let Some(gw) = &expected.gateway {
+        let actual: Vec<IpAddr> = iface
+            .gateway
+            .iter()
+            .flat_map(|g| {
+                g.ipv4
+                    .iter()
+                    .map(|ip| IpAddr::V4(*ip))
+                    .chain(g.ipv6.iter().map(|ip| IpAddr::V6(*ip)))
+            })
+            .collect();
+        let pass = gw.parse::<IpAddr>().is_ok_and(|ip| actual.contains(&ip));
+        let found: Vec<String> = actual.iter().map(|ip| ip.to_string()).collect();
+        findings.push(check(
+            "gateway",
+            pass,
+            format!("expected {}, found [{}]", gw, found.join(", ")),
+        ));
+    }
+    findings
+}
+
+/// Match "addr/prefix" exactly, or a bare "addr" against the address part only
+fn has_addr(actual: &[String], want: &str) -> bool {
+    if want.contains('/') {
+        actual.iter().any(|a| a == want)
+    } else {
+        actual.iter().any(|a| a.split('/').next() == Some(want))
+    }
+}
+
+fn fmt_opt<T: std::fmt::Display>(v: Option<T>) -> String {
+    v.map(|v| v.to_string()).unwrap_or_else(|| "(none)".into())
+}
@@ -0,0 +1,39 @@
+use anyhow::{Result, bail};
+use serde::Serialize;
+
+use crate::cli::{Cli, OutputFormat, VpnArgs};
+use crate::collector;
+use crate::collector::iface::VpnHeuristic;
+use crate::renderer::tree::{TreeOptions, print_vpn_tree};
+
+/// VPN heuristic result for one interface
+#[derive(Debug, Serialize)]
+pub struct VpnReport {
+    pub interface: String,
+    #[serde(flatten)]
+    pub heuristic: VpnHeuristic,
+}
+
+/// Show the VPN heuristic with its scoring breakdown
+pub fn show_vpn_heuristic(cli: &Cli, args: &VpnArgs) -> Result<()> {
+    let iface = match &args.iface {
+        Some(name) => collector::iface::get_interface_by_name(name),
+        None => collector::iface::get_default_interface(),
+    };
+    let Some(iface) = iface else {
+        match &args.iface {
+            Some(name) => bail!("Interface '{}' not found", name),
+            None => bail!("No default interface found"),
+        }
+    };
+    let report = VpnReport {
+        heuristic: collector::iface::detect_vpn_like(&iface),
+        interface: iface.name,
+    };
+    match cli.format {
+        OutputFormat::Json => crate::renderer::json::print_json(&report, cli.use_color())?,
+        OutputFormat::Yaml => println!("{}", serde_yaml::to_string(&report)?),
+        OutputFormat::Tree => print_vpn_tree(&report, &TreeOptions::from_cli(cli)),
+    }
+    Ok(())
+}
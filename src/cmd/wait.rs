@@ -0,0 +1,55 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use netdev::Interface;
+use netdev::interface::OperState;
+
+use crate::cli::{Cli, WaitArgs};
+use crate::collector;
+
+/// Block until the default interface is up with a global address
+pub fn wait_online(_cli: &Cli, args: &WaitArgs) -> Result<()> {
+    let start = Instant::now();
+    let timeout = args.timeout;
+    let interval = args.interval.max(Duration::from_millis(100));
+    loop {
+        let status = match collector::iface::get_default_interface() {
+            Some(iface) if is_online(&iface) => {
+                if !args.quiet {
+                    eprintln!("{} is online", iface.name);
+                }
+                return Ok(());
+            }
+            Some(iface) => format!(
+                "default interface {} not ready (state: {:?})",
+                iface.name, iface.oper_state
+            ),
+            None => "no default interface".to_string(),
+        };
+        let elapsed = start.elapsed();
+        if elapsed >= timeout {
+            anyhow::bail!(
+                "Timed out after {} waiting for network ({})",
+                humantime::format_duration(timeout),
+                status
+            );
+        }
+        if !args.quiet {
+            eprintln!("Waiting for network: {} [{}s]", status, elapsed.as_secs());
+        }
+        std::thread::sleep(interval.min(timeout - elapsed));
+    }
+}
+
+/// Up and has at least one global (non-loopback, non-link-local) address
+fn is_online(iface: &Interface) -> bool {
+    let has_global_v4 = iface
+        .ipv4
+        .iter()
+        .any(|n| !n.addr().is_loopback() && !n.addr().is_link_local());
+    let has_global_v6 = iface
+        .ipv6
+        .iter()
+        .any(|n| !n.addr().is_loopback() && !n.addr().is_unicast_link_local());
+    iface.oper_state == OperState::Up && (has_global_v4 || has_global_v6)
+}
@@ -0,0 +1,116 @@
+#[cfg(target_os = "linux")]
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::model::addr::{Ipv6Lifetime, MulticastGroup};
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: read IPv6 address lifetimes from `ip -6 -o addr show`
+pub fn collect_ipv6_lifetimes() -> Vec<Ipv6Lifetime> {
+    let output = std::process::Command::new("ip")
+        .args(["-6", "-o", "addr", "show"])
+        .output();
+    match output {
+        Ok(out) if out.status.success() => String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .filter_map(parse_ip_addr_line)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn collect_ipv6_lifetimes() -> Vec<Ipv6Lifetime> {
+    Vec::new()
+}
+
+#[cfg(target_os = "linux")]
+/// e.g. "2: eth0    inet6 2001:db8::2/64 scope global dynamic \   valid_lft 86379sec preferred_lft 14379sec"
+fn parse_ip_addr_line(line: &str) -> Option<Ipv6Lifetime> {
+    let mut parts = line.split_whitespace().skip(1);
+    let iface = parts.next()?.to_string();
+    let mut addr = None;
+    let mut valid = None;
+    let mut preferred = None;
+    while let Some(tok) = parts.next() {
+        match tok {
+            "inet6" => addr = parts.next()?.split('/').next()?.parse().ok(),
+            "valid_lft" => valid = parts.next().map(parse_lft),
+            "preferred_lft" => preferred = parts.next().map(parse_lft),
+            _ => {}
+        }
+    }
+    Some(Ipv6Lifetime {
+        iface,
+        addr: addr?,
+        preferred_secs: preferred?,
+        valid_secs: valid?,
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn parse_lft(v: &str) -> Option<u64> {
+    v.trim_end_matches("sec").parse().ok()
+}
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: multicast memberships from `/proc/net/igmp` and `/proc/net/igmp6`
+pub fn collect_multicast_groups() -> Vec<MulticastGroup> {
+    let mut groups = Vec::new();
+    if let Ok(text) = std::fs::read_to_string("/proc/net/igmp") {
+        groups.extend(parse_igmp(&text));
+    }
+    if let Ok(text) = std::fs::read_to_string("/proc/net/igmp6") {
+        groups.extend(text.lines().filter_map(parse_igmp6_line));
+    }
+    groups
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn collect_multicast_groups() -> Vec<MulticastGroup> {
+    Vec::new()
+}
+
+#[cfg(target_os = "linux")]
+/// Device lines ("4\teth0      :     1      V3") are followed by indented group
+/// lines ("\t\t\t\t010000E0     1 0:00000000\t\t0"); groups are hex in host byte order
+fn parse_igmp(text: &str) -> Vec<MulticastGroup> {
+    let mut groups = Vec::new();
+    let mut iface: Option<String> = None;
+    for line in text.lines().skip(1) {
+        if !line.starts_with('\t') {
+            iface = line
+                .split_whitespace()
+                .nth(1)
+                .map(|s| s.trim_end_matches(':').to_string());
+            continue;
+        }
+        let mut parts = line.split_whitespace();
+        let (Some(name), Some(hex), Some(users)) = (&iface, parts.next(), parts.next()) else {
+            continue;
+        };
+        let Ok(raw) = u32::from_str_radix(hex, 16) else {
+            continue;
+        };
+        groups.push(MulticastGroup {
+            iface: name.clone(),
+            group: IpAddr::V4(Ipv4Addr::from(raw.to_ne_bytes())),
+            users: users.parse().unwrap_or(0),
+        });
+    }
+    groups
+}
+
+#[cfg(target_os = "linux")]
+/// e.g. "4    eth0            ff0200000000000000000001ff000002     1 00000004 0"
+fn parse_igmp6_line(line: &str) -> Option<MulticastGroup> {
+    let mut parts = line.split_whitespace().skip(1);
+    let iface = parts.next()?.to_string();
+    let hex = parts.next()?;
+    let users = parts.next()?.parse().unwrap_or(0);
+    let raw = u128::from_str_radix(hex, 16).ok()?;
+    Some(MulticastGroup {
+        iface,
+        group: IpAddr::V6(Ipv6Addr::from(raw)),
+        users,
+    })
+}
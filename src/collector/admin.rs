@@ -0,0 +1,116 @@
+use anyhow::Result;
+
+/// Check if changing the admin state is supported on this platform
+pub fn is_supported() -> bool {
+    cfg!(target_os = "linux")
+}
+
+/// Check if the current process may change the admin state: root, or on
+/// Linux a non-root process holding CAP_NET_ADMIN (e.g. via setcap)
+#[cfg(target_os = "linux")]
+pub fn is_elevated() -> bool {
+    let root = unsafe { libc::geteuid() == 0 };
+    root || std::fs::read_to_string("/proc/self/status")
+        .is_ok_and(|status| has_cap_net_admin(&status))
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+pub fn is_elevated() -> bool {
+    unsafe { libc::geteuid() == 0 }
+}
+
+#[cfg(not(unix))]
+pub fn is_elevated() -> bool {
+    false
+}
+
+/// CAP_NET_ADMIN bit in the capability sets of /proc/<pid>/status
+#[cfg(target_os = "linux")]
+const CAP_NET_ADMIN: u32 = 12;
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: whether the `CapEff:` mask of a /proc/<pid>/status text
+/// includes CAP_NET_ADMIN
+fn has_cap_net_admin(status: &str) -> bool {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("CapEff:"))
+        .and_then(|mask| u64::from_str_radix(mask.trim(), 16).ok())
+        .is_some_and(|mask| mask & (1 << CAP_NET_ADMIN) != 0)
+}
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: toggle IFF_UP via SIOCGIFFLAGS/SIOCSIFFLAGS
+pub fn set_admin_state(name: &str, up: bool) -> Result<()> {
+    use std::io::Error;
+
+    if name.len() >= libc::IFNAMSIZ {
+        anyhow::bail!("Interface name '{}' is too long", name);
+    }
+    unsafe {
+        let fd = libc::socket(libc::AF_INET, libc::SOCK_DGRAM, 0);
+        if fd < 0 {
+            return Err(Error::last_os_error().into());
+        }
+        let mut req: libc::ifreq = std::mem::zeroed();
+        for (dst, src) in req.ifr_name.iter_mut().zip(name.bytes()) {
+            *dst = src as libc::c_char;
+        }
+        let res = (|| -> std::io::Result<()> {
+            if libc::ioctl(fd, libc::SIOCGIFFLAGS, &mut req) < 0 {
+                return Err(Error::last_os_error());
+            }
+            let flags = req.ifr_ifru.ifru_flags;
+            req.ifr_ifru.ifru_flags = if up {
+                flags | libc::IFF_UP as libc::c_short
+            } else {
+                flags & !(libc::IFF_UP as libc::c_short)
+            };
+            if libc::ioctl(fd, libc::SIOCSIFFLAGS, &mut req) < 0 {
+                return Err(Error::last_os_error());
+            }
+            Ok(())
+        })();
+        libc::close(fd);
+        match res {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => Err(anyhow::anyhow!(
+                "Permission denied: changing admin state of '{}' requires root/CAP_NET_ADMIN",
+                name
+            )),
+            Err(e) => Err(anyhow::anyhow!(
+                "Failed to change admin state of '{}': {}",
+                name,
+                e
+            )),
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn set_admin_state(_name: &str, _up: bool) -> Result<()> {
+    anyhow::bail!("Changing interface admin state is not supported on this platform")
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cap_net_admin_is_read_from_cap_eff() {
+        let status = |eff: &str| {
+            format!(
+                "Name:\tnifa\nCapInh:\t0000000000000000\nCapPrm:\t0000000000001000\nCapEff:\t{eff}\n"
+            )
+        };
+        // Only CAP_NET_ADMIN (setcap cap_net_admin+ep)
+        assert!(has_cap_net_admin(&status("0000000000001000")));
+        // Full root set
+        assert!(has_cap_net_admin(&status("000001ffffffffff")));
+        // Permitted but not effective does not count
+        assert!(!has_cap_net_admin(&status("0000000000000000")));
+        // CAP_NET_RAW (bit 13) alone is not enough
+        assert!(!has_cap_net_admin(&status("0000000000002000")));
+        assert!(!has_cap_net_admin("Name:\tnifa\n"));
+    }
+}
@@ -0,0 +1,70 @@
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::{Duration, Instant};
+
+use crate::model::connectivity::{Connectivity, TcpProbe};
+
+/// Well-known public endpoints probed by `--probe`
+const PROBE_TARGETS: [SocketAddr; 3] = [
+    SocketAddr::new(IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1)), 443),
+    SocketAddr::new(IpAddr::V4(Ipv4Addr::new(8, 8, 8, 8)), 443),
+    SocketAddr::new(IpAddr::V4(Ipv4Addr::new(9, 9, 9, 9)), 443),
+];
+
+/// Upper bound for each TCP connect
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// TCP-connect to the well-known targets concurrently
+pub async fn probe_connectivity() -> Connectivity {
+    let handles: Vec<_> = PROBE_TARGETS
+        .iter()
+        .map(|target| tokio::spawn(probe(*target)))
+        .collect();
+    let mut probes = Vec::with_capacity(handles.len());
+    for h in handles {
+        if let Ok(p) = h.await {
+            probes.push(p);
+        }
+    }
+    Connectivity::from_probes(probes)
+}
+
+async fn probe(target: SocketAddr) -> TcpProbe {
+    let start = Instant::now();
+    let connected = tokio::time::timeout(PROBE_TIMEOUT, tokio::net::TcpStream::connect(target))
+        .await
+        .is_ok_and(|r| r.is_ok());
+    TcpProbe {
+        target,
+        reachable: connected,
+        latency_ms: connected.then(|| start.elapsed().as_millis() as u64),
+    }
+}
+
+/// TCP port used to probe gateways. A refused connection still proves the
+/// gateway answered, so no service needs to listen on it.
+const GATEWAY_PROBE_PORT: u16 = 80;
+
+/// Blocking TCP-connect reachability check for a gateway. Returns the RTT,
+/// or None when the gateway did not answer within `timeout`.
+pub fn probe_gateway(ip: IpAddr, if_index: u32, timeout: Duration) -> Option<Duration> {
+    let target = match ip {
+        // Link-local next hops need the interface as scope
+        IpAddr::V6(v6) => SocketAddr::V6(std::net::SocketAddrV6::new(
+            v6,
+            GATEWAY_PROBE_PORT,
+            0,
+            if v6.is_unicast_link_local() {
+                if_index
+            } else {
+                0
+            },
+        )),
+        IpAddr::V4(_) => SocketAddr::new(ip, GATEWAY_PROBE_PORT),
+    };
+    let start = Instant::now();
+    match std::net::TcpStream::connect_timeout(&target, timeout) {
+        Ok(_) => Some(start.elapsed()),
+        Err(e) if e.kind() == std::io::ErrorKind::ConnectionRefused => Some(start.elapsed()),
+        Err(_) => None,
+    }
+}
@@ -0,0 +1,143 @@
+use netdev::Interface;
+use serde::{Deserialize, Serialize};
+
+/// DHCP lease information of an interface
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DhcpInfo {
+    /// "dhcp" when a matching lease was found, "unsupported" where lease files
+    /// are not read (anything but Linux), otherwise "unknown"
+    pub source: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server: Option<String>,
+    /// When the lease file was last written (obtained or renewed); the lease
+    /// itself carries no timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_modified: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<String>,
+}
+
+impl DhcpInfo {
+    fn without_lease(source: &str) -> Self {
+        DhcpInfo {
+            source: source.into(),
+            server: None,
+            file_modified: None,
+            expires: None,
+        }
+    }
+}
+
+/// Look up DHCP lease info for the given interface
+pub fn dhcp_info(iface: &Interface) -> DhcpInfo {
+    if !cfg!(target_os = "linux") {
+        return DhcpInfo::without_lease("unsupported");
+    }
+    lease_info(iface).unwrap_or_else(|| DhcpInfo::without_lease("unknown"))
+}
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: read systemd-networkd, NetworkManager and dhclient lease files
+fn lease_info(iface: &Interface) -> Option<DhcpInfo> {
+    let networkd = std::path::PathBuf::from(format!("/run/systemd/netif/leases/{}", iface.index));
+    if let Some(info) = read_keyvalue_lease(&networkd) {
+        return Some(info);
+    }
+    let nm_suffix = format!("-{}.lease", iface.name);
+    for path in lease_files("/var/lib/NetworkManager") {
+        let is_iface = path
+            .file_name()
+            .is_some_and(|n| n.to_string_lossy().ends_with(&nm_suffix));
+        if is_iface && let Some(info) = read_keyvalue_lease(&path) {
+            return Some(info);
+        }
+    }
+    for dir in ["/var/lib/dhcp", "/var/lib/dhclient", "/var/lib/dhcpcd"] {
+        for path in lease_files(dir) {
+            if let Some(info) = read_dhclient_lease(&path, iface) {
+                return Some(info);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(not(target_os = "linux"))]
+fn lease_info(_iface: &Interface) -> Option<DhcpInfo> {
+    None
+}
+
+#[cfg(target_os = "linux")]
+fn lease_files(dir: &str) -> Vec<std::path::PathBuf> {
+    std::fs::read_dir(dir)
+        .map(|rd| {
+            rd.filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|p| p.extension().is_some_and(|x| x == "lease" || x == "leases"))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(target_os = "linux")]
+/// systemd-networkd / NetworkManager internal lease (KEY=VALUE lines)
+fn read_keyvalue_lease(path: &std::path::Path) -> Option<DhcpInfo> {
+    use std::time::Duration;
+
+    let text = std::fs::read_to_string(path).ok()?;
+    let get = |key: &str| {
+        text.lines()
+            .find_map(|l| l.strip_prefix(key)?.strip_prefix('='))
+            .map(|v| v.trim().to_string())
+    };
+    get("ADDRESS")?;
+    // The lease holds no timestamp; the file is (re)written when the lease is
+    // obtained or renewed, so LIFETIME counts from its mtime
+    let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok();
+    let expires = match (
+        modified,
+        get("LIFETIME").and_then(|v| v.parse::<u64>().ok()),
+    ) {
+        (Some(t), Some(secs)) => Some(t + Duration::from_secs(secs)),
+        _ => None,
+    };
+    Some(DhcpInfo {
+        source: "dhcp".into(),
+        server: get("SERVER_ADDRESS"),
+        file_modified: modified.map(|t| humantime::format_rfc3339_seconds(t).to_string()),
+        expires: expires.map(|t| humantime::format_rfc3339_seconds(t).to_string()),
+    })
+}
+
+#[cfg(target_os = "linux")]
+/// ISC dhclient lease database (last `lease { ... }` block for the interface wins)
+fn read_dhclient_lease(path: &std::path::Path, iface: &Interface) -> Option<DhcpInfo> {
+    let text = std::fs::read_to_string(path).ok()?;
+    let iface_line = format!("interface \"{}\";", iface.name);
+    let block = text
+        .split("lease {")
+        .filter(|b| b.contains(&iface_line))
+        .last()?;
+    let value = |key: &str| {
+        block.lines().find_map(|l| {
+            let v = l.trim().strip_prefix(key)?.trim_end_matches(';').trim();
+            Some(v.to_string())
+        })
+    };
+    // e.g. "expire 2 2024/01/02 03:04:05;" (weekday, date, time in UTC)
+    let when = |key: &str| {
+        value(key).map(|v| {
+            let parts: Vec<&str> = v.split_whitespace().collect();
+            match parts.as_slice() {
+                [_, date, time] => format!("{} {} UTC", date.replace('/', "-"), time),
+                _ => v,
+            }
+        })
+    };
+    Some(DhcpInfo {
+        source: "dhcp".into(),
+        server: value("option dhcp-server-identifier "),
+        file_modified: None,
+        expires: when("expire "),
+    })
+}
@@ -0,0 +1,213 @@
+use std::net::{IpAddr, Ipv4Addr};
+use std::time::{Duration, Instant};
+
+use netdev::Interface;
+
+use crate::model::dns::{DohHeuristic, DohProbe, InterfaceSearchDomains};
+
+/// Well-known public DoH endpoints probed by `--check-doh`
+const DOH_ENDPOINTS: [&str; 3] = [
+    "https://cloudflare-dns.com/dns-query",
+    "https://dns.google/dns-query",
+    "https://dns.quad9.net/dns-query",
+];
+
+/// Upper bound for each DoH probe
+const DOH_PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// systemd-resolved stub listener
+const RESOLVED_STUB: IpAddr = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 53));
+
+/// Guess whether DNS is resolved over DoH/DoT from the local resolver setup
+pub fn detect_doh(default_iface: Option<&Interface>) -> DohHeuristic {
+    let mut resolvers: Vec<IpAddr> = default_iface
+        .map(|i| i.dns_servers.clone())
+        .unwrap_or_default();
+    for ip in resolv_conf_nameservers() {
+        if !resolvers.contains(&ip) {
+            resolvers.push(ip);
+        }
+    }
+    let stub_resolver = !resolvers.is_empty() && resolvers.iter().all(|ip| ip.is_loopback());
+    let doh_likely = stub_resolver && resolvers.iter().any(|ip| *ip != RESOLVED_STUB);
+    DohHeuristic {
+        resolvers,
+        stub_resolver,
+        dot_configured: dot_configured(),
+        doh_likely,
+        probes: Vec::new(),
+    }
+}
+
+/// Probe the well-known DoH endpoints concurrently
+pub async fn probe_doh_endpoints() -> Vec<DohProbe> {
+    let client = match reqwest::Client::builder()
+        .timeout(DOH_PROBE_TIMEOUT)
+        .build()
+    {
+        Ok(c) => c,
+        Err(_) => return Vec::new(),
+    };
+    let handles: Vec<_> = DOH_ENDPOINTS
+        .iter()
+        .map(|url| {
+            let client = client.clone();
+            tokio::spawn(async move { probe(&client, url).await })
+        })
+        .collect();
+    let mut probes = Vec::with_capacity(handles.len());
+    for h in handles {
+        if let Ok(p) = h.await {
+            probes.push(p);
+        }
+    }
+    probes
+}
+
+async fn probe(client: &reqwest::Client, url: &str) -> DohProbe {
+    let start = Instant::now();
+    // Any HTTP response (even 4xx for a query-less request) means the endpoint is reachable
+    match client
+        .get(url)
+        .header("accept", "application/dns-message")
+        .send()
+        .await
+    {
+        Ok(_) => DohProbe {
+            url: url.to_string(),
+            reachable: true,
+            latency_ms: Some(start.elapsed().as_millis() as u64),
+            error: None,
+        },
+        Err(e) => DohProbe {
+            url: url.to_string(),
+            reachable: false,
+            latency_ms: None,
+            error: Some(if e.is_timeout() {
+                "timeout".into()
+            } else if e.is_connect() {
+                "connect failed".into()
+            } else {
+                e.to_string()
+            }),
+        },
+    }
+}
+
+#[cfg(unix)]
+fn resolv_conf_nameservers() -> Vec<IpAddr> {
+    std::fs::read_to_string("/etc/resolv.conf")
+        .map(|text| {
+            text.lines()
+                .filter_map(|l| l.trim().strip_prefix("nameserver"))
+                .filter_map(|v| v.trim().parse().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(not(unix))]
+fn resolv_conf_nameservers() -> Vec<IpAddr> {
+    Vec::new()
+}
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: `DNSOverTLS=` in systemd-resolved configuration
+fn dot_configured() -> bool {
+    let mut files = vec![std::path::PathBuf::from("/etc/systemd/resolved.conf")];
+    if let Ok(rd) = std::fs::read_dir("/etc/systemd/resolved.conf.d") {
+        files.extend(rd.filter_map(|e| e.ok()).map(|e| e.path()));
+    }
+    files.iter().any(|path| {
+        std::fs::read_to_string(path).is_ok_and(|text| {
+            text.lines().any(|l| {
+                l.trim()
+                    .strip_prefix("DNSOverTLS=")
+                    .is_some_and(|v| matches!(v.trim(), "yes" | "opportunistic" | "true"))
+            })
+        })
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn dot_configured() -> bool {
+    false
+}
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: per-link search domains from systemd-resolved (`resolvectl domain`)
+pub fn collect_search_domains() -> Vec<InterfaceSearchDomains> {
+    match std::process::Command::new("resolvectl")
+        .arg("domain")
+        .output()
+    {
+        Ok(out) if out.status.success() => {
+            parse_resolvectl_domains(&String::from_utf8_lossy(&out.stdout))
+        }
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(target_os = "windows")]
+/// Windows-specific: connection-specific DNS suffix of each interface
+pub fn collect_search_domains() -> Vec<InterfaceSearchDomains> {
+    let output = std::process::Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-Command",
+            "Get-DnsClient | ForEach-Object { \"$($_.InterfaceAlias)`t$($_.ConnectionSpecificSuffix)\" }",
+        ])
+        .output();
+    match output {
+        Ok(out) if out.status.success() => String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .filter_map(|line| {
+                let (alias, suffix) = line.trim_end().split_once('\t')?;
+                (!suffix.is_empty()).then(|| InterfaceSearchDomains {
+                    iface: alias.to_string(),
+                    domains: vec![suffix.to_string()],
+                })
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+pub fn collect_search_domains() -> Vec<InterfaceSearchDomains> {
+    Vec::new()
+}
+
+/// Search domains of the given interface among collected `all` entries.
+/// On Windows they are keyed by the friendly name.
+pub fn search_domains_of<'a>(all: &'a [InterfaceSearchDomains], iface: &Interface) -> &'a [String] {
+    all.iter()
+        .find(|d| d.iface == iface.name || iface.friendly_name.as_deref() == Some(d.iface.as_str()))
+        .map_or(&[], |d| d.domains.as_slice())
+}
+
+#[cfg(target_os = "linux")]
+/// e.g.
+/// ```text
+/// Global:
+/// Link 2 (eth0): corp.example.com ~.
+/// ```
+/// Routing-only domains (`~` prefix) are not search domains and are skipped.
+fn parse_resolvectl_domains(text: &str) -> Vec<InterfaceSearchDomains> {
+    text.lines()
+        .filter_map(|line| {
+            let rest = line.strip_prefix("Link ")?;
+            let (_, rest) = rest.split_once('(')?;
+            let (name, domains) = rest.split_once("):")?;
+            let domains: Vec<String> = domains
+                .split_whitespace()
+                .filter(|d| !d.starts_with('~'))
+                .map(str::to_string)
+                .collect();
+            (!domains.is_empty()).then(|| InterfaceSearchDomains {
+                iface: name.to_string(),
+                domains,
+            })
+        })
+        .collect()
+}
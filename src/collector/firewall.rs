@@ -0,0 +1,85 @@
+use crate::model::firewall::InterfaceZone;
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: active firewalld zones from `firewall-cmd --get-active-zones`
+pub fn collect_zones() -> Vec<InterfaceZone> {
+    let output = std::process::Command::new("firewall-cmd")
+        .arg("--get-active-zones")
+        .output();
+    match output {
+        Ok(out) if out.status.success() => {
+            parse_active_zones(&String::from_utf8_lossy(&out.stdout))
+        }
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(target_os = "windows")]
+/// Windows-specific: network category of each connection profile
+pub fn collect_zones() -> Vec<InterfaceZone> {
+    let output = std::process::Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-Command",
+            "Get-NetConnectionProfile | ForEach-Object { \"$($_.InterfaceAlias)`t$($_.NetworkCategory)\" }",
+        ])
+        .output();
+    match output {
+        Ok(out) if out.status.success() => String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .filter_map(|line| {
+                let (alias, category) = line.trim_end().split_once('\t')?;
+                Some(InterfaceZone {
+                    iface: alias.to_string(),
+                    kind: "profile".into(),
+                    name: category.to_string(),
+                })
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+pub fn collect_zones() -> Vec<InterfaceZone> {
+    Vec::new()
+}
+
+/// Zone/profile of the given interface among collected `zones`.
+/// On Windows profiles are keyed by the friendly name.
+pub fn zone_of<'a>(
+    zones: &'a [InterfaceZone],
+    iface: &netdev::Interface,
+) -> Option<&'a InterfaceZone> {
+    zones
+        .iter()
+        .find(|z| z.iface == iface.name || iface.friendly_name.as_deref() == Some(z.iface.as_str()))
+}
+
+#[cfg(target_os = "linux")]
+/// e.g.
+/// ```text
+/// public
+///   interfaces: eth0 eth1
+/// trusted
+///   sources: 10.0.0.0/8
+/// ```
+fn parse_active_zones(text: &str) -> Vec<InterfaceZone> {
+    let mut zones = Vec::new();
+    let mut current: Option<&str> = None;
+    for line in text.lines() {
+        if !line.starts_with(char::is_whitespace) {
+            // Zone header, possibly suffixed with " (default)"
+            current = line.split_whitespace().next();
+        } else if let (Some(zone), Some(ifaces)) =
+            (current, line.trim().strip_prefix("interfaces:"))
+        {
+            zones.extend(ifaces.split_whitespace().map(|iface| InterfaceZone {
+                iface: iface.to_string(),
+                kind: "zone".into(),
+                name: zone.to_string(),
+            }));
+        }
+    }
+    zones
+}
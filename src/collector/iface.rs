@@ -1,5 +1,9 @@
-use netdev::Interface;
 use netdev::interface::InterfaceType;
+use netdev::ipnet::{IpNet, Ipv4Net};
+use netdev::{Interface, MacAddr};
+use serde::Serialize;
+
+use crate::model::iface::LinkMode;
 
 /// Common patterns that indicate a VPN/tunnel adapter
 const VPN_NAME_PATTERNS: &[&str] = &[
@@ -20,46 +24,431 @@ const VPN_NAME_PATTERNS: &[&str] = &[
     "expressvpn",
 ];
 
+/// Name prefixes (lowercase) of virtual interfaces and the subtype they indicate.
+/// Checked in order, so more specific prefixes come first.
+const VIRTUAL_NAME_PATTERNS: &[(&str, &str)] = &[
+    ("vethernet", "hyper-v"),
+    ("veth", "veth"),
+    ("docker", "docker"),
+    ("br-", "docker"),
+    ("virbr", "libvirt bridge"),
+    ("lxcbr", "lxc bridge"),
+    ("lxdbr", "lxc bridge"),
+    ("vnet", "kvm tap"),
+    ("macvtap", "kvm tap"),
+    ("cni", "kubernetes cni"),
+    ("flannel", "kubernetes cni"),
+    ("cali", "kubernetes cni"),
+    ("cilium", "kubernetes cni"),
+    ("weave", "kubernetes cni"),
+    ("kube-", "kubernetes cni"),
+    ("wg", "wireguard"),
+    ("wireguard", "wireguard"),
+    ("tailscale", "tailscale"),
+    ("zt", "zerotier"),
+    ("utun", "tun"),
+    ("tun", "tun"),
+    ("tap", "tap"),
+    ("vmnet", "vmware"),
+    ("vmware", "vmware"),
+    ("vboxnet", "virtualbox"),
+    ("virtualbox", "virtualbox"),
+    ("ifb", "ifb"),
+    ("dummy", "dummy"),
+    ("bond", "bond"),
+    ("team", "team"),
+    ("vlan", "vlan"),
+    ("br", "bridge"),
+];
+
+/// Subtype of a virtual interface (e.g. "veth", "docker", "wireguard"),
+/// from its name/friendly name and type. None for physical interfaces and
+/// virtual ones that match nothing. `kernel_virtual` overrides netdev's
+/// `is_physical()` (see `kernel_virtual_names`).
+pub fn classify_virtual(iface: &Interface, kernel_virtual: bool) -> Option<&'static str> {
+    if iface.is_loopback() || iface.if_type == InterfaceType::Loopback {
+        return Some("loopback");
+    }
+    if iface.is_physical() && !kernel_virtual {
+        return None;
+    }
+    let names = [Some(&iface.name), iface.friendly_name.as_ref()];
+    let by_name = names
+        .into_iter()
+        .flatten()
+        .find_map(|name| virtual_kind_of_name(name));
+    by_name.or(match iface.if_type {
+        InterfaceType::Tunnel => Some("tunnel"),
+        InterfaceType::Ppp => Some("ppp"),
+        InterfaceType::Bridge => Some("bridge"),
+        _ => None,
+    })
+}
+
+/// Subtype for the first matching `VIRTUAL_NAME_PATTERNS` prefix (case-insensitive)
+fn virtual_kind_of_name(name: &str) -> Option<&'static str> {
+    let name = name.to_lowercase();
+    VIRTUAL_NAME_PATTERNS
+        .iter()
+        .find(|(prefix, _)| name.starts_with(prefix))
+        .map(|(_, kind)| *kind)
+}
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: names of `ifaces` without backing hardware, i.e. those under
+/// `/sys/devices/virtual/net`. netdev's `is_physical()` accepts any interface with
+/// carrier, so veth/ifb pairs pass it.
+pub fn kernel_virtual_names(ifaces: &[Interface]) -> Vec<String> {
+    ifaces
+        .iter()
+        .filter(|iface| {
+            std::fs::canonicalize(format!("/sys/class/net/{}", iface.name))
+                .map(|path| path.starts_with("/sys/devices/virtual"))
+                .unwrap_or(false)
+        })
+        .map(|iface| iface.name.clone())
+        .collect()
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn kernel_virtual_names(_ifaces: &[Interface]) -> Vec<String> {
+    Vec::new()
+}
+
 pub fn collect_all_interfaces() -> Vec<Interface> {
     netdev::get_interfaces()
 }
 
-pub fn get_default_interface() -> Option<Interface> {
-    match netdev::get_default_interface() {
-        Ok(iface) => Some(iface),
-        Err(_) => None,
+/// `--debug-dump`: print netdev's interfaces unmodified (`{:#?}`) to stderr
+pub fn dump_raw_interfaces() {
+    for iface in netdev::get_interfaces() {
+        eprintln!("{:#?}", iface);
     }
 }
 
-pub fn get_interface_by_name(name: &str) -> Option<Interface> {
-    let interfaces = netdev::get_interfaces();
-    for iface in interfaces {
-        if iface.name == name {
-            return Some(iface);
+/// Relative difference above which RX/TX link speeds count as asymmetric
+const SPEED_ASYMMETRY_TOLERANCE: f64 = 0.01;
+
+/// Whether RX and TX link speeds differ beyond a small tolerance
+/// (false when either speed is unknown)
+pub fn has_asymmetric_speed(iface: &Interface) -> bool {
+    match (iface.transmit_speed, iface.receive_speed) {
+        (Some(tx), Some(rx)) if tx.max(rx) > 0 => {
+            tx.abs_diff(rx) as f64 / tx.max(rx) as f64 > SPEED_ASYMMETRY_TOLERANCE
+        }
+        _ => false,
+    }
+}
+
+/// Pairs of interfaces whose IPv4 networks overlap, with the overlapping range
+/// (the narrower of the two networks). Loopback and link-local networks are ignored.
+pub fn find_overlapping_subnets(ifaces: &[Interface]) -> Vec<(String, String, IpNet)> {
+    let nets = |iface: &Interface| -> Vec<Ipv4Net> {
+        iface
+            .ipv4
+            .iter()
+            .filter(|n| !n.addr().is_loopback() && !n.addr().is_link_local())
+            .map(|n| n.trunc())
+            .collect()
+    };
+    let mut overlaps = Vec::new();
+    for (i, a) in ifaces.iter().enumerate() {
+        let a_nets = nets(a);
+        for b in &ifaces[i + 1..] {
+            for nb in nets(b) {
+                for na in &a_nets {
+                    let overlap = if na.contains(&nb) {
+                        nb
+                    } else if nb.contains(na) {
+                        *na
+                    } else {
+                        continue;
+                    };
+                    let entry = (a.name.clone(), b.name.clone(), IpNet::V4(overlap));
+                    if !overlaps.contains(&entry) {
+                        overlaps.push(entry);
+                    }
+                }
+            }
         }
     }
+    overlaps
+}
+
+/// All interfaces with the default interface first, then the rest by index
+pub fn collect_all_interfaces_default_first() -> Vec<Interface> {
+    let mut ifaces = collect_all_interfaces();
+    sort_default_first(&mut ifaces);
+    ifaces
+}
+
+/// Put the default interface first, then order the rest by index
+pub fn sort_default_first(ifaces: &mut [Interface]) {
+    ifaces.sort_by_key(|iface| (!iface.default, iface.index));
+}
+
+pub fn get_default_interface() -> Option<Interface> {
+    netdev::get_default_interface().ok()
+}
+
+pub fn get_interface_by_name(name: &str) -> Option<Interface> {
+    netdev::get_interfaces()
+        .into_iter()
+        .find(|iface| iface.name == name)
+}
+
+/// Sort IPv4/IPv6 addresses and DNS servers numerically for deterministic output.
+/// IPv6 scope IDs are reordered together with their addresses.
+pub fn sort_addresses(iface: &mut Interface) {
+    iface.ipv4.sort_by_key(|n| (n.addr(), n.prefix_len()));
+    if iface.ipv6_scope_ids.len() == iface.ipv6.len() {
+        let mut pairs: Vec<_> = iface
+            .ipv6
+            .drain(..)
+            .zip(iface.ipv6_scope_ids.drain(..))
+            .collect();
+        pairs.sort_by_key(|(n, _)| (n.addr(), n.prefix_len()));
+        (iface.ipv6, iface.ipv6_scope_ids) = pairs.into_iter().unzip();
+    } else {
+        iface.ipv6.sort_by_key(|n| (n.addr(), n.prefix_len()));
+    }
+    iface.dns_servers.sort();
+}
+
+/// Copy of the interface with sorted addresses (see `sort_addresses`)
+pub fn with_sorted_addresses(iface: &Interface) -> Interface {
+    let mut iface = iface.clone();
+    sort_addresses(&mut iface);
+    iface
+}
+
+/// `IFF_PROMISC` (same value on Linux, macOS and the BSDs)
+const IFF_PROMISC: u32 = 0x100;
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: read `/sys/class/net/<if>/flags`, falling back to the netdev flags
+pub fn is_promiscuous(iface: &Interface) -> Option<bool> {
+    let flags = std::fs::read_to_string(format!("/sys/class/net/{}/flags", iface.name))
+        .ok()
+        .and_then(|s| u32::from_str_radix(s.trim().trim_start_matches("0x"), 16).ok())
+        .unwrap_or(iface.flags);
+    Some(flags & IFF_PROMISC != 0)
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+pub fn is_promiscuous(iface: &Interface) -> Option<bool> {
+    Some(iface.flags & IFF_PROMISC != 0)
+}
+
+#[cfg(not(unix))]
+pub fn is_promiscuous(_iface: &Interface) -> Option<bool> {
+    None
+}
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: duplex and autonegotiation via the `ETHTOOL_GSET` ioctl,
+/// with duplex falling back to `/sys/class/net/<if>/duplex`.
+/// None when neither is known (loopback, most virtual devices).
+pub fn link_mode(iface: &Interface) -> Option<LinkMode> {
+    let (duplex, autoneg) = ethtool_gset(&iface.name).unwrap_or_default();
+    let duplex = duplex.or_else(|| {
+        std::fs::read_to_string(format!("/sys/class/net/{}/duplex", iface.name))
+            .ok()
+            .map(|s| s.trim().to_string())
+            .filter(|s| s == "full" || s == "half")
+    });
+    if duplex.is_none() && autoneg.is_none() {
+        return None;
+    }
+    Some(LinkMode {
+        iface: iface.name.clone(),
+        duplex,
+        autoneg,
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn link_mode(_iface: &Interface) -> Option<LinkMode> {
     None
 }
 
-#[derive(Debug)]
+#[cfg(target_os = "linux")]
+/// Duplex ("full"/"half") and autoneg from `struct ethtool_cmd`
+fn ethtool_gset(name: &str) -> Option<(Option<String>, Option<bool>)> {
+    const ETHTOOL_GSET: u32 = 0x1;
+    const DUPLEX_HALF: u8 = 0x00;
+    const DUPLEX_FULL: u8 = 0x01;
+
+    /// `struct ethtool_cmd` from <linux/ethtool.h>
+    #[repr(C)]
+    #[derive(Default)]
+    struct EthtoolCmd {
+        cmd: u32,
+        supported: u32,
+        advertising: u32,
+        speed: u16,
+        duplex: u8,
+        port: u8,
+        phy_address: u8,
+        transceiver: u8,
+        autoneg: u8,
+        mdio_support: u8,
+        maxtxpkt: u32,
+        maxrxpkt: u32,
+        speed_hi: u16,
+        eth_tp_mdix: u8,
+        eth_tp_mdix_ctrl: u8,
+        lp_advertising: u32,
+        reserved: [u32; 2],
+    }
+
+    if name.len() >= libc::IFNAMSIZ {
+        return None;
+    }
+    let mut cmd = EthtoolCmd {
+        cmd: ETHTOOL_GSET,
+        ..Default::default()
+    };
+    unsafe {
+        let fd = libc::socket(libc::AF_INET, libc::SOCK_DGRAM | libc::SOCK_CLOEXEC, 0);
+        if fd < 0 {
+            return None;
+        }
+        let mut req: libc::ifreq = std::mem::zeroed();
+        for (dst, src) in req.ifr_name.iter_mut().zip(name.bytes()) {
+            *dst = src as libc::c_char;
+        }
+        req.ifr_ifru.ifru_data = (&mut cmd as *mut EthtoolCmd).cast();
+        let res = libc::ioctl(fd, libc::SIOCETHTOOL, &mut req);
+        libc::close(fd);
+        if res < 0 {
+            return None;
+        }
+    }
+    let duplex = match cmd.duplex {
+        DUPLEX_FULL => Some("full".to_string()),
+        DUPLEX_HALF => Some("half".to_string()),
+        _ => None,
+    };
+    Some((duplex, Some(cmd.autoneg != 0)))
+}
+
+/// Resolve the MACs of IPv6 gateways from the NDP neighbor cache, as
+/// (interface, MAC) pairs. Only interfaces whose gateway MAC netdev left unset
+/// (all-zero) are looked up, and the cache is read at most once.
+pub fn gateway_macs_from_ndp(ifaces: &[Interface]) -> Vec<(String, String)> {
+    let wanted: Vec<&Interface> = ifaces
+        .iter()
+        .filter(|i| {
+            i.gateway
+                .as_ref()
+                .is_some_and(|gw| gw.mac_addr == MacAddr::zero() && !gw.ipv6.is_empty())
+        })
+        .collect();
+    if wanted.is_empty() {
+        return Vec::new();
+    }
+    let neighbors = crate::collector::neighbor::collect_neighbors();
+    wanted
+        .into_iter()
+        .filter_map(|iface| {
+            let gw = iface.gateway.as_ref()?;
+            neighbors
+                .iter()
+                .find(|n| {
+                    n.iface == iface.name
+                        && matches!(n.ip, std::net::IpAddr::V6(ip) if gw.ipv6.contains(&ip))
+                })
+                .map(|n| (iface.name.clone(), n.mac.clone()))
+        })
+        .collect()
+}
+
+/// Aggregate traffic counters across interfaces
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TrafficTotals {
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub interfaces: usize,
+    pub include_loopback: bool,
+}
+
+/// Sum rx/tx bytes of all interfaces with stats.
+/// Loopback is excluded unless `include_loopback` is set.
+pub fn total_traffic(ifaces: &[Interface], include_loopback: bool) -> TrafficTotals {
+    let mut totals = TrafficTotals {
+        include_loopback,
+        ..Default::default()
+    };
+    for iface in ifaces
+        .iter()
+        .filter(|i| include_loopback || !i.is_loopback())
+    {
+        if let Some(st) = &iface.stats {
+            totals.rx_bytes = totals.rx_bytes.saturating_add(st.rx_bytes);
+            totals.tx_bytes = totals.tx_bytes.saturating_add(st.tx_bytes);
+            totals.interfaces += 1;
+        }
+    }
+    totals
+}
+
+/// Traffic counters summed over all interfaces of one type
+#[derive(Debug, Serialize)]
+pub struct TypeTotals {
+    pub if_type: InterfaceType,
+    /// Interfaces of this type that report stats
+    pub interfaces: usize,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+}
+
+/// Sum rx/tx bytes per interface type, busiest type first.
+/// Interfaces without stats are skipped.
+pub fn totals_by_type(ifaces: &[Interface]) -> Vec<TypeTotals> {
+    let mut groups: Vec<TypeTotals> = Vec::new();
+    for iface in ifaces {
+        let Some(st) = &iface.stats else {
+            continue;
+        };
+        let idx = match groups.iter().position(|g| g.if_type == iface.if_type) {
+            Some(idx) => idx,
+            None => {
+                groups.push(TypeTotals {
+                    if_type: iface.if_type,
+                    interfaces: 0,
+                    rx_bytes: 0,
+                    tx_bytes: 0,
+                });
+                groups.len() - 1
+            }
+        };
+        let group = &mut groups[idx];
+        group.rx_bytes = group.rx_bytes.saturating_add(st.rx_bytes);
+        group.tx_bytes = group.tx_bytes.saturating_add(st.tx_bytes);
+        group.interfaces += 1;
+    }
+    groups.sort_by_key(|g| std::cmp::Reverse(g.rx_bytes.saturating_add(g.tx_bytes)));
+    groups
+}
+
+#[derive(Debug, Serialize)]
 pub struct VpnHeuristic {
     pub is_vpn_like: bool,
-    #[allow(dead_code)]
+    /// Sum of all signal points (VPN-like at 5 or more)
     pub score: i32,
-    #[allow(dead_code)]
-    pub signals: Vec<String>,
+    /// Contributing signals with the points each one added
+    pub signals: Vec<(String, i32)>,
 }
 
 /// Check if the given interface looks like a VPN interface using simple heuristics.
 pub fn detect_vpn_like(default_if: &Interface) -> VpnHeuristic {
-    let mut score = 0;
-    let mut sig = Vec::new();
+    let mut sig: Vec<(String, i32)> = Vec::new();
 
     // Check InterfaceType
     match default_if.if_type {
         InterfaceType::Tunnel | InterfaceType::Ppp | InterfaceType::ProprietaryVirtual => {
-            score += 4;
-            sig.push(format!("type={:?}", default_if.if_type));
+            sig.push((format!("type={:?}", default_if.if_type), 4));
         }
         _ => {}
     }
@@ -67,26 +456,24 @@ pub fn detect_vpn_like(default_if: &Interface) -> VpnHeuristic {
     // Check name patterns
     let name = default_if.name.to_lowercase();
     if VPN_NAME_PATTERNS.iter().any(|p| name.contains(p)) {
-        score += 3;
-        sig.push(format!("name={}", default_if.name));
+        sig.push((format!("name={}", default_if.name), 3));
     }
 
     // Check friendly_name patterns
     if let Some(fname) = &default_if.friendly_name {
         let fname_lower = fname.to_lowercase();
         if VPN_NAME_PATTERNS.iter().any(|p| fname_lower.contains(p)) {
-            score += 3;
-            sig.push(format!("friendly_name={}", fname));
+            sig.push((format!("friendly_name={}", fname), 3));
         }
     }
 
     // Check MTU
-    if let Some(mtu) = default_if.mtu {
-        if mtu < 1500 {
-            // Likely VPN MTU
-            score += if (1410..=1460).contains(&mtu) { 2 } else { 1 };
-            sig.push(format!("mtu={}", mtu));
-        }
+    if let Some(mtu) = default_if.mtu
+        && mtu < 1500
+    {
+        // Likely VPN MTU
+        let points = if (1410..=1460).contains(&mtu) { 2 } else { 1 };
+        sig.push((format!("mtu={}", mtu), points));
     }
 
     // Check if IPv4 is 10/8 or 100.64/10
@@ -96,8 +483,7 @@ pub fn detect_vpn_like(default_if: &Interface) -> VpnHeuristic {
         oct[0] == 10 || (oct[0] == 100 && (oct[1] & 0b1100_0000) == 0b0100_0000) // 100.64.0.0/10
     });
     if v4_inner_like {
-        score += 2;
-        sig.push("ipv4=private(10/8 or 100.64/10)".into());
+        sig.push(("ipv4=private(10/8 or 100.64/10)".into(), 2));
     }
 
     // Check if DNS is 100.64/10
@@ -110,8 +496,7 @@ pub fn detect_vpn_like(default_if: &Interface) -> VpnHeuristic {
         }
     });
     if dns_any_100_64 {
-        score += 1;
-        sig.push("dns=100.64.0.0/10".into());
+        sig.push(("dns=100.64.0.0/10".into(), 1));
     }
 
     // Check if the type is clearly not physical
@@ -122,11 +507,11 @@ pub fn detect_vpn_like(default_if: &Interface) -> VpnHeuristic {
         | InterfaceType::FastEthernetT
         | InterfaceType::FastEthernetFx => {}
         _ => {
-            score += 1;
-            sig.push(format!("type-other={:?}", default_if.if_type));
+            sig.push((format!("type-other={:?}", default_if.if_type), 1));
         }
     }
 
+    let score = sig.iter().map(|(_, points)| points).sum();
     let is_vpn_like = score >= 5;
     VpnHeuristic {
         is_vpn_like,
@@ -134,3 +519,151 @@ pub fn detect_vpn_like(default_if: &Interface) -> VpnHeuristic {
         signals: sig,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn iface_with(name: &str, v4: &[&str], v6: &[&str]) -> Interface {
+        let mut iface = Interface::dummy();
+        iface.name = name.into();
+        iface.ipv4 = v4.iter().map(|n| n.parse().unwrap()).collect();
+        iface.ipv6 = v6.iter().map(|n| n.parse().unwrap()).collect();
+        iface
+    }
+
+    #[test]
+    fn sort_addresses_keeps_ipv6_scope_ids_attached() {
+        let mut iface = iface_with(
+            "eth0",
+            &["192.168.1.20/24", "10.0.0.5/8"],
+            &["fe80::2/64", "2001:db8::1/64", "fe80::1/64"],
+        );
+        iface.ipv6_scope_ids = vec![3, 0, 2];
+        sort_addresses(&mut iface);
+
+        let v4: Vec<String> = iface.ipv4.iter().map(|n| n.to_string()).collect();
+        assert_eq!(v4, ["10.0.0.5/8", "192.168.1.20/24"]);
+        let v6: Vec<String> = iface.ipv6.iter().map(|n| n.to_string()).collect();
+        assert_eq!(v6, ["2001:db8::1/64", "fe80::1/64", "fe80::2/64"]);
+        assert_eq!(iface.ipv6_scope_ids, [0, 2, 3]);
+    }
+
+    #[test]
+    fn sort_default_first_orders_default_then_index() {
+        let mut ifaces: Vec<Interface> = [(3, false), (1, false), (7, true), (2, false)]
+            .into_iter()
+            .map(|(index, default)| {
+                let mut iface = Interface::dummy();
+                iface.index = index;
+                iface.default = default;
+                iface
+            })
+            .collect();
+        sort_default_first(&mut ifaces);
+        let order: Vec<u32> = ifaces.iter().map(|i| i.index).collect();
+        assert_eq!(order, [7, 1, 2, 3]);
+    }
+
+    #[test]
+    fn virtual_kind_of_representative_names() {
+        assert_eq!(virtual_kind_of_name("veth3a1b2c"), Some("veth"));
+        assert_eq!(
+            virtual_kind_of_name("vEthernet (Default Switch)"),
+            Some("hyper-v")
+        );
+        assert_eq!(virtual_kind_of_name("docker0"), Some("docker"));
+        assert_eq!(virtual_kind_of_name("br-5f2c9a1e7b3d"), Some("docker"));
+        assert_eq!(virtual_kind_of_name("br0"), Some("bridge"));
+        assert_eq!(virtual_kind_of_name("wg0"), Some("wireguard"));
+        assert_eq!(virtual_kind_of_name("utun3"), Some("tun"));
+        assert_eq!(virtual_kind_of_name("eth0"), None);
+        assert_eq!(virtual_kind_of_name("enp3s0"), None);
+    }
+
+    #[test]
+    fn virtual_name_patterns_are_not_shadowed() {
+        // An earlier prefix that is also a prefix of a later one would hide it
+        for (i, (later, _)) in VIRTUAL_NAME_PATTERNS.iter().enumerate() {
+            for (earlier, _) in &VIRTUAL_NAME_PATTERNS[..i] {
+                assert!(
+                    !later.starts_with(earlier),
+                    "{:?} is shadowed by {:?}",
+                    later,
+                    earlier
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn overlapping_subnets_disjoint() {
+        let ifaces = [
+            iface_with("eth0", &["192.168.1.5/24"], &[]),
+            iface_with("eth1", &["192.168.2.5/24"], &[]),
+            iface_with("wg0", &["10.8.0.2/24"], &[]),
+        ];
+        assert!(find_overlapping_subnets(&ifaces).is_empty());
+    }
+
+    #[test]
+    fn overlapping_subnets_nested() {
+        let ifaces = [
+            iface_with("eth0", &["10.1.2.3/16"], &[]),
+            iface_with("tun0", &["10.1.5.1/24"], &[]),
+        ];
+        assert_eq!(
+            find_overlapping_subnets(&ifaces),
+            vec![(
+                "eth0".to_string(),
+                "tun0".to_string(),
+                "10.1.5.0/24".parse().unwrap()
+            )]
+        );
+    }
+
+    #[test]
+    fn overlapping_subnets_same_network() {
+        let ifaces = [
+            iface_with("eth0", &["192.168.1.5/24"], &[]),
+            iface_with("wlan0", &["192.168.1.77/24"], &[]),
+        ];
+        assert_eq!(
+            find_overlapping_subnets(&ifaces),
+            vec![(
+                "eth0".to_string(),
+                "wlan0".to_string(),
+                "192.168.1.0/24".parse().unwrap()
+            )]
+        );
+    }
+
+    #[test]
+    fn overlapping_subnets_ignores_ipv6_loopback_and_link_local() {
+        let ifaces = [
+            iface_with("eth0", &[], &["fd00::2/64"]),
+            iface_with("eth1", &[], &["fd00::3/64"]),
+            iface_with("lo", &["127.0.0.1/8"], &["::1/128"]),
+            iface_with("lo2", &["127.0.0.2/8"], &[]),
+            iface_with("eth2", &["169.254.10.1/16"], &[]),
+            iface_with("eth3", &["169.254.20.1/16"], &[]),
+        ];
+        assert!(find_overlapping_subnets(&ifaces).is_empty());
+    }
+
+    #[test]
+    fn loopback_is_classified_as_loopback() {
+        let mut lo = Interface::dummy();
+        lo.name = "lo".into();
+        lo.if_type = InterfaceType::Loopback;
+        assert_eq!(classify_virtual(&lo, false), Some("loopback"));
+    }
+
+    #[test]
+    fn kernel_virtual_is_classified_by_name() {
+        let mut veth = Interface::dummy();
+        veth.name = "veth1a2b".into();
+        veth.if_type = InterfaceType::Ethernet;
+        assert_eq!(classify_virtual(&veth, true), Some("veth"));
+    }
+}
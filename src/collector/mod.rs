@@ -1,12 +1,105 @@
+pub mod addr;
+pub mod admin;
+pub mod connectivity;
+pub mod dhcp;
+pub mod dns;
+pub mod firewall;
 pub mod iface;
+pub mod neighbor;
+pub mod ra;
+pub mod remote;
+pub mod stats;
 pub mod sys;
+pub mod topology;
 
 use anyhow::Result;
+use netdev::Interface;
 
+use crate::model::addr::{Ipv6Lifetime, Ipv6RaInfo, MulticastGroup};
+use crate::model::dns::InterfaceSearchDomains;
+use crate::model::firewall::InterfaceZone;
+use crate::model::iface::LinkMode;
 use crate::model::snapshot::Snapshot;
 
+/// Per-interface data that comes from external tools (firewall-cmd, resolvectl, `ip`,
+/// powershell). Collected once per command and looked up by interface while rendering,
+/// so renderers and `Snapshot::new` do no I/O of their own.
+#[derive(Debug, Clone, Default)]
+pub struct IfaceLookups {
+    pub zones: Vec<InterfaceZone>,
+    pub search_domains: Vec<InterfaceSearchDomains>,
+    pub ipv6_lifetimes: Vec<Ipv6Lifetime>,
+    pub ra: Vec<Ipv6RaInfo>,
+    pub multicast_groups: Vec<MulticastGroup>,
+    pub link_modes: Vec<LinkMode>,
+    /// (interface, MAC) of IPv6 gateways that netdev reports without a MAC
+    pub gateway_macs: Vec<(String, String)>,
+    /// Names of interfaces the kernel reports as virtual (no backing hardware)
+    pub kernel_virtual: Vec<String>,
+}
+
+impl IfaceLookups {
+    /// Collect the lookups for `ifaces` (per-interface queries cover only these)
+    pub fn collect(ifaces: &[Interface]) -> Self {
+        IfaceLookups {
+            zones: firewall::collect_zones(),
+            search_domains: dns::collect_search_domains(),
+            ipv6_lifetimes: addr::collect_ipv6_lifetimes(),
+            ra: ra::collect_ra(ifaces),
+            multicast_groups: addr::collect_multicast_groups(),
+            link_modes: ifaces.iter().filter_map(iface::link_mode).collect(),
+            gateway_macs: iface::gateway_macs_from_ndp(ifaces),
+            kernel_virtual: iface::kernel_virtual_names(ifaces),
+        }
+    }
+
+    /// Zone/profile of the given interface
+    pub fn zone_of(&self, iface: &Interface) -> Option<&InterfaceZone> {
+        firewall::zone_of(&self.zones, iface)
+    }
+
+    /// DNS search domains of the given interface (empty when none are known)
+    pub fn search_domains_of(&self, iface: &Interface) -> &[String] {
+        dns::search_domains_of(&self.search_domains, iface)
+    }
+
+    /// IPv6 router advertisement flags of the given interface
+    pub fn ra_of(&self, iface: &Interface) -> Option<&Ipv6RaInfo> {
+        self.ra.iter().find(|ra| ra.iface == iface.name)
+    }
+
+    /// Duplex/autonegotiation of the given interface
+    pub fn link_mode_of(&self, iface: &Interface) -> Option<&LinkMode> {
+        self.link_modes.iter().find(|m| m.iface == iface.name)
+    }
+
+    /// Virtual subtype of the given interface (see `iface::classify_virtual`)
+    pub fn virtual_kind_of(&self, iface: &Interface) -> Option<&'static str> {
+        iface::classify_virtual(iface, self.kernel_virtual.contains(&iface.name))
+    }
+
+    /// Gateway MAC of the given interface from the NDP cache
+    pub fn gateway_mac_of(&self, iface: &Interface) -> Option<&str> {
+        self.gateway_macs
+            .iter()
+            .find(|(name, _)| *name == iface.name)
+            .map(|(_, mac)| mac.as_str())
+    }
+
+    /// Multicast memberships of the given interface
+    pub fn multicast_groups_of<'a>(
+        &'a self,
+        iface: &'a Interface,
+    ) -> impl Iterator<Item = &'a MulticastGroup> + 'a {
+        self.multicast_groups
+            .iter()
+            .filter(|g| g.iface == iface.name)
+    }
+}
+
 pub fn collect_snapshot() -> Result<Snapshot> {
     let sys = crate::collector::sys::system_info();
     let interfaces = crate::collector::iface::collect_all_interfaces();
-    Ok(Snapshot { sys, interfaces })
+    let lookups = IfaceLookups::collect(&interfaces);
+    Ok(Snapshot::new(sys, interfaces, &lookups))
 }
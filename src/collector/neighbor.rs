@@ -0,0 +1,92 @@
+use crate::model::neighbor::NeighborEntry;
+
+/// Collect neighbor cache entries learned on the given interface
+pub fn neighbors_of(iface: &str) -> Vec<NeighborEntry> {
+    collect_neighbors()
+        .into_iter()
+        .filter(|n| n.iface == iface)
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: `ip -s neigh show` (ARP + NDP), falling back to `/proc/net/arp` (IPv4 only)
+pub fn collect_neighbors() -> Vec<NeighborEntry> {
+    let output = std::process::Command::new("ip")
+        .args(["-s", "neigh", "show"])
+        .output();
+    match output {
+        Ok(out) if out.status.success() => String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .filter_map(parse_ip_neigh_line)
+            .collect(),
+        _ => read_proc_net_arp(),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn collect_neighbors() -> Vec<NeighborEntry> {
+    Vec::new()
+}
+
+#[cfg(target_os = "linux")]
+/// e.g. "192.0.2.1 dev eth0 lladdr 02:00:00:00:00:01 router used 30/30/12 probes 1 REACHABLE"
+fn parse_ip_neigh_line(line: &str) -> Option<NeighborEntry> {
+    let mut parts = line.split_whitespace();
+    let ip = parts.next()?.parse().ok()?;
+    let mut iface = None;
+    let mut mac = None;
+    let mut state = None;
+    let mut age_secs = None;
+    while let Some(tok) = parts.next() {
+        match tok {
+            "dev" => iface = parts.next().map(str::to_string),
+            "lladdr" => mac = parts.next().map(str::to_string),
+            // used/confirmed/updated seconds; iproute2 may glue "probes" onto it
+            "used" => {
+                age_secs = parts
+                    .next()
+                    .and_then(|v| v.split('/').nth(2))
+                    .and_then(|v| {
+                        v.trim_end_matches(|c: char| !c.is_ascii_digit())
+                            .parse()
+                            .ok()
+                    })
+            }
+            "router" | "proxy" => {}
+            s if s.chars().all(|c| c.is_ascii_uppercase()) => state = Some(s.to_string()),
+            _ => {}
+        }
+    }
+    // Entries without a link-layer address are incomplete/failed
+    Some(NeighborEntry {
+        ip,
+        mac: mac?,
+        iface: iface?,
+        state,
+        age_secs,
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn read_proc_net_arp() -> Vec<NeighborEntry> {
+    let Ok(text) = std::fs::read_to_string("/proc/net/arp") else {
+        return Vec::new();
+    };
+    text.lines()
+        .skip(1)
+        .filter_map(|line| {
+            let cols: Vec<&str> = line.split_whitespace().collect();
+            // IP, HW type, Flags, HW address, Mask, Device
+            if cols.len() < 6 || cols[2] == "0x0" {
+                return None;
+            }
+            Some(NeighborEntry {
+                ip: cols[0].parse().ok()?,
+                mac: cols[3].to_string(),
+                iface: cols[5].to_string(),
+                state: None,
+                age_secs: None,
+            })
+        })
+        .collect()
+}
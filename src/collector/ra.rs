@@ -0,0 +1,272 @@
+use netdev::Interface;
+
+use crate::model::addr::Ipv6RaInfo;
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: RA flags from one rtnetlink dump (`IFLA_INET6_FLAGS`) and router
+/// lifetimes from one `ip -6 route` call, for all of `ifaces` at once
+pub fn collect_ra(ifaces: &[Interface]) -> Vec<Ipv6RaInfo> {
+    const IF_RA_RCVD: u32 = 0x20;
+    const IF_RA_MANAGED: u32 = 0x40;
+    const IF_RA_OTHERCONF: u32 = 0x80;
+
+    let flags = netlink::inet6_flags();
+    if flags.is_empty() {
+        return Vec::new();
+    }
+    let lifetimes = router_lifetimes();
+    ifaces
+        .iter()
+        .filter_map(|iface| {
+            let (_, flags) = flags.iter().find(|(index, _)| *index == iface.index)?;
+            let received = flags & IF_RA_RCVD != 0;
+            Some(Ipv6RaInfo {
+                iface: iface.name.clone(),
+                received,
+                managed: flags & IF_RA_MANAGED != 0,
+                other_config: flags & IF_RA_OTHERCONF != 0,
+                router_lifetime_secs: lifetimes
+                    .iter()
+                    .find(|(name, _)| received && *name == iface.name)
+                    .map(|(_, secs)| *secs),
+            })
+        })
+        .collect()
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn collect_ra(_ifaces: &[Interface]) -> Vec<Ipv6RaInfo> {
+    Vec::new()
+}
+
+#[cfg(target_os = "linux")]
+/// Remaining lifetime of each RA-learned default route, by interface name
+fn router_lifetimes() -> Vec<(String, u64)> {
+    let out = std::process::Command::new("ip")
+        .args(["-6", "route", "show", "default", "proto", "ra"])
+        .output();
+    match out {
+        Ok(out) if out.status.success() => String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .filter_map(parse_route_line)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(target_os = "linux")]
+/// e.g. "default via fe80::1 dev eth0 metric 1024 expires 1795sec hoplimit 64 pref medium"
+fn parse_route_line(line: &str) -> Option<(String, u64)> {
+    let mut dev = None;
+    let mut expires = None;
+    let mut parts = line.split_whitespace();
+    while let Some(tok) = parts.next() {
+        match tok {
+            "dev" => dev = parts.next(),
+            "expires" => expires = parts.next()?.trim_end_matches("sec").parse().ok(),
+            _ => {}
+        }
+    }
+    Some((dev?.to_string(), expires?))
+}
+
+#[cfg(target_os = "linux")]
+mod netlink {
+    use std::mem::size_of;
+
+    const IFLA_INET6_FLAGS: u16 = 1;
+    const NLA_ALIGNTO: usize = 4;
+
+    /// `struct ifinfomsg` from <linux/rtnetlink.h>
+    #[repr(C)]
+    struct IfInfoMsg {
+        family: u8,
+        pad: u8,
+        kind: u16,
+        index: i32,
+        flags: u32,
+        change: u32,
+    }
+
+    #[repr(C)]
+    struct Request {
+        header: libc::nlmsghdr,
+        msg: IfInfoMsg,
+    }
+
+    fn align(len: usize) -> usize {
+        (len + NLA_ALIGNTO - 1) & !(NLA_ALIGNTO - 1)
+    }
+
+    /// Dump AF_INET6 link info: `IFLA_INET6_FLAGS` by interface index
+    pub fn inet6_flags() -> Vec<(u32, u32)> {
+        let fd = unsafe {
+            libc::socket(
+                libc::AF_NETLINK,
+                libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+                libc::NETLINK_ROUTE,
+            )
+        };
+        if fd < 0 {
+            return Vec::new();
+        }
+        let mut flags = Vec::new();
+        query(fd, &mut flags);
+        unsafe { libc::close(fd) };
+        flags
+    }
+
+    fn query(fd: i32, flags: &mut Vec<(u32, u32)>) {
+        let req = Request {
+            header: libc::nlmsghdr {
+                nlmsg_len: size_of::<Request>() as u32,
+                nlmsg_type: libc::RTM_GETLINK,
+                nlmsg_flags: (libc::NLM_F_REQUEST | libc::NLM_F_DUMP) as u16,
+                nlmsg_seq: 1,
+                nlmsg_pid: 0,
+            },
+            msg: IfInfoMsg {
+                family: libc::AF_INET6 as u8,
+                pad: 0,
+                kind: 0,
+                index: 0,
+                flags: 0,
+                change: 0,
+            },
+        };
+        let sent = unsafe {
+            libc::send(
+                fd,
+                &req as *const Request as *const libc::c_void,
+                size_of::<Request>(),
+                0,
+            )
+        };
+        if sent < 0 {
+            return;
+        }
+
+        let mut buf = vec![0u8; 32 * 1024];
+        loop {
+            let n = unsafe { libc::recv(fd, buf.as_mut_ptr() as *mut libc::c_void, buf.len(), 0) };
+            if n <= 0 || parse_messages(&buf[..n as usize], flags) {
+                return;
+            }
+        }
+    }
+
+    /// Parse one `recv` worth of netlink messages into `flags`; true once the dump is done
+    pub(super) fn parse_messages(mut data: &[u8], flags: &mut Vec<(u32, u32)>) -> bool {
+        let hdr = size_of::<libc::nlmsghdr>();
+        while data.len() >= hdr {
+            let len = u32::from_ne_bytes([data[0], data[1], data[2], data[3]]) as usize;
+            let kind = u16::from_ne_bytes([data[4], data[5]]);
+            if len < hdr || len > data.len() {
+                return true;
+            }
+            match kind as i32 {
+                libc::NLMSG_DONE | libc::NLMSG_ERROR => return true,
+                _ if kind == libc::RTM_NEWLINK => flags.extend(parse_link(&data[hdr..len])),
+                _ => {}
+            }
+            data = &data[align(len).min(data.len())..];
+        }
+        false
+    }
+
+    /// Parse an RTM_NEWLINK body: ifinfomsg, then attributes
+    fn parse_link(body: &[u8]) -> Option<(u32, u32)> {
+        let hdr = size_of::<IfInfoMsg>();
+        if body.len() < hdr {
+            return None;
+        }
+        let index = i32::from_ne_bytes(body[4..8].try_into().ok()?) as u32;
+        let protinfo = find_attr(&body[hdr..], libc::IFLA_PROTINFO)?;
+        let flags = find_attr(protinfo, IFLA_INET6_FLAGS)?;
+        Some((index, u32::from_ne_bytes(flags.get(0..4)?.try_into().ok()?)))
+    }
+
+    /// Find the payload of the attribute `kind` in a run of `rtattr`s
+    fn find_attr(mut attrs: &[u8], kind: u16) -> Option<&[u8]> {
+        while attrs.len() >= 4 {
+            let len = u16::from_ne_bytes(attrs[0..2].try_into().ok()?) as usize;
+            let attr_kind = u16::from_ne_bytes(attrs[2..4].try_into().ok()?) & 0x3fff;
+            if len < 4 || len > attrs.len() {
+                return None;
+            }
+            if attr_kind == kind {
+                return Some(&attrs[4..len]);
+            }
+            attrs = &attrs[align(len).min(attrs.len())..];
+        }
+        None
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn route_line_gives_device_and_lifetime() {
+        assert_eq!(
+            parse_route_line(
+                "default via fe80::1 dev eth0 metric 1024 expires 1795sec hoplimit 64 pref medium"
+            ),
+            Some(("eth0".to_string(), 1795))
+        );
+        // A static default route has no lifetime
+        assert_eq!(
+            parse_route_line("default via fd00::1 dev eth0 metric 1024 pref medium"),
+            None
+        );
+    }
+
+    /// `rtattr` with its payload padded to 4 bytes
+    fn attr(kind: u16, payload: &[u8]) -> Vec<u8> {
+        let mut out = ((4 + payload.len()) as u16).to_ne_bytes().to_vec();
+        out.extend(kind.to_ne_bytes());
+        out.extend(payload);
+        out.resize(out.len().next_multiple_of(4), 0);
+        out
+    }
+
+    /// Netlink message: nlmsghdr, then `body`
+    fn message(kind: u16, body: &[u8]) -> Vec<u8> {
+        let mut out = ((16 + body.len()) as u32).to_ne_bytes().to_vec();
+        out.extend(kind.to_ne_bytes());
+        out.extend((libc::NLM_F_MULTI as u16).to_ne_bytes());
+        out.extend(1u32.to_ne_bytes());
+        out.extend(0u32.to_ne_bytes());
+        out.extend(body);
+        out
+    }
+
+    /// AF_INET6 RTM_NEWLINK as in a dump: ifinfomsg, IFLA_IFNAME, then IFLA_PROTINFO
+    /// holding IFLA_INET6_FLAGS and IFLA_INET6_CACHEINFO
+    fn newlink(index: i32, name: &str, flags: u32) -> Vec<u8> {
+        let mut body = vec![libc::AF_INET6 as u8, 0, 0, 0];
+        body.extend(index.to_ne_bytes());
+        body.extend([0u8; 8]);
+        body.extend(attr(3, format!("{}\0", name).as_bytes()));
+        let protinfo = [attr(1, &flags.to_ne_bytes()), attr(5, &[0u8; 16])].concat();
+        body.extend(attr(libc::IFLA_PROTINFO, &protinfo));
+        message(libc::RTM_NEWLINK, &body)
+    }
+
+    #[test]
+    fn link_dump_gives_inet6_flags_by_index() {
+        // lo is ready but got no RA; eth0 received one with the O flag set
+        let first = [
+            newlink(1, "lo", 0x8000_0000),
+            newlink(2, "eth0", 0x8000_00a0),
+        ]
+        .concat();
+        let last = message(libc::NLMSG_DONE as u16, &0i32.to_ne_bytes());
+
+        let mut flags = Vec::new();
+        assert!(!netlink::parse_messages(&first, &mut flags));
+        assert!(netlink::parse_messages(&last, &mut flags));
+        assert_eq!(flags, [(1, 0x8000_0000), (2, 0x8000_00a0)]);
+    }
+}
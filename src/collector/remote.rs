@@ -0,0 +1,33 @@
+use anyhow::{Context, Result, bail};
+
+use crate::model::snapshot::Snapshot;
+
+/// Collect a snapshot on a remote host by running `nifa -f json export` over SSH.
+/// BatchMode keeps ssh from prompting inside the TUI.
+pub fn fetch_snapshot_ssh(target: &str) -> Result<Snapshot> {
+    let out = std::process::Command::new("ssh")
+        .args([
+            "-o",
+            "BatchMode=yes",
+            "-o",
+            "ConnectTimeout=5",
+            target,
+            "nifa",
+            "-f",
+            "json",
+            "export",
+        ])
+        .stdin(std::process::Stdio::null())
+        .output()
+        .context("Failed to run ssh")?;
+    if !out.status.success() {
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        bail!(
+            "ssh {} exited with {}: {}",
+            target,
+            out.status,
+            stderr.lines().last().unwrap_or("").trim()
+        );
+    }
+    serde_json::from_slice(&out.stdout).with_context(|| format!("Invalid snapshot from {}", target))
+}
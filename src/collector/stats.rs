@@ -0,0 +1,174 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Packet/error/drop counters not exposed by netdev's InterfaceStats
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct LinkCounters {
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_dropped: u64,
+    #[serde(default)]
+    pub collisions: u64,
+}
+
+impl LinkCounters {
+    /// Error/drop/collision counters by name, for reporting increases
+    pub fn error_counters(&self) -> [(&'static str, u64); 5] {
+        [
+            ("rx_errors", self.rx_errors),
+            ("tx_errors", self.tx_errors),
+            ("rx_dropped", self.rx_dropped),
+            ("tx_dropped", self.tx_dropped),
+            ("collisions", self.collisions),
+        ]
+    }
+}
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: read `/sys/class/net/<if>/statistics/*`
+pub fn link_counters(name: &str) -> Option<LinkCounters> {
+    let dir = std::path::Path::new("/sys/class/net")
+        .join(name)
+        .join("statistics");
+    let read = |file: &str| -> Option<u64> {
+        std::fs::read_to_string(dir.join(file))
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    };
+    Some(LinkCounters {
+        rx_packets: read("rx_packets")?,
+        tx_packets: read("tx_packets")?,
+        rx_errors: read("rx_errors").unwrap_or(0),
+        tx_errors: read("tx_errors").unwrap_or(0),
+        rx_dropped: read("rx_dropped").unwrap_or(0),
+        tx_dropped: read("tx_dropped").unwrap_or(0),
+        collisions: read("collisions").unwrap_or(0),
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn link_counters(_name: &str) -> Option<LinkCounters> {
+    None
+}
+
+/// Recorded byte counters replayed by `monitor --stats-source`: blocks of
+/// `name rx_bytes tx_bytes` lines separated by blank lines, one block per tick
+/// (`#` starts a comment). Works with a named pipe; a tick waits for the next
+/// block. After the last block the counters stay where they were.
+pub struct StatsReplay {
+    source: String,
+    reader: Box<dyn BufRead>,
+    line_no: usize,
+    last: Vec<(String, u64, u64)>,
+}
+
+impl StatsReplay {
+    pub fn open(path: &Path) -> Result<Self> {
+        let file = File::open(path).with_context(|| format!("open {}", path.display()))?;
+        Ok(StatsReplay {
+            source: path.display().to_string(),
+            reader: Box::new(BufReader::new(file)),
+            line_no: 0,
+            last: Vec::new(),
+        })
+    }
+
+    /// Counters for the next tick (the previous block again at end of input)
+    pub fn next_tick(&mut self) -> Result<&[(String, u64, u64)]> {
+        let mut block = Vec::new();
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if self.reader.read_line(&mut line)? == 0 {
+                break;
+            }
+            self.line_no += 1;
+            let text = line.split('#').next().unwrap_or("").trim();
+            if text.is_empty() {
+                if block.is_empty() {
+                    continue;
+                }
+                break;
+            }
+            let parsed = match text.split_whitespace().collect::<Vec<_>>()[..] {
+                [name, rx, tx] => rx
+                    .parse()
+                    .ok()
+                    .zip(tx.parse().ok())
+                    .map(|(rx, tx)| (name.to_string(), rx, tx)),
+                _ => None,
+            };
+            let Some(entry) = parsed else {
+                anyhow::bail!(
+                    "{}:{}: expected `name rx_bytes tx_bytes`, got '{}'",
+                    self.source,
+                    self.line_no,
+                    text
+                );
+            };
+            block.push(entry);
+        }
+        if !block.is_empty() {
+            self.last = block;
+        }
+        Ok(&self.last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Write `text` to a unique temp file
+    fn temp_file(name: &str, text: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("nifa-test-{}-{}", std::process::id(), name));
+        std::fs::write(&path, text).unwrap();
+        path
+    }
+
+    #[test]
+    fn replay_yields_one_block_per_tick_then_repeats_the_last() {
+        let path = temp_file(
+            "replay-blocks",
+            "# recorded on host1\neth0 100 200\nwlan0 5 6\n\n\neth0 1100 700 # +1000/+500\nwlan0 5 6\n",
+        );
+        let mut replay = StatsReplay::open(&path).unwrap();
+        let first = replay.next_tick().unwrap().to_vec();
+        let second = replay.next_tick().unwrap().to_vec();
+        let third = replay.next_tick().unwrap().to_vec();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            first,
+            [("eth0".to_string(), 100, 200), ("wlan0".to_string(), 5, 6)]
+        );
+        assert_eq!(
+            second,
+            [("eth0".to_string(), 1100, 700), ("wlan0".to_string(), 5, 6)]
+        );
+        assert_eq!(third, second);
+    }
+
+    #[test]
+    fn replay_rejects_malformed_lines_with_position() {
+        let path = temp_file("replay-bad", "eth0 100 200\n\neth0 lots 200\n");
+        let mut replay = StatsReplay::open(&path).unwrap();
+        assert!(replay.next_tick().is_ok());
+        let err = replay.next_tick().unwrap_err().to_string();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(
+            err.ends_with(":3: expected `name rx_bytes tx_bytes`, got 'eth0 lots 200'"),
+            "{err}"
+        );
+    }
+}
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Missing fields fall back to defaults so older/newer snapshots still load
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SysInfo {
     pub hostname: String,
     pub os_type: String,
@@ -11,9 +14,36 @@ pub struct SysInfo {
     pub bitness: String,
     pub architecture: String,
     pub proxy: ProxyEnv,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub boot_time: Option<String>,
+    /// Seconds since boot
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uptime_secs: Option<u64>,
+    /// IP forwarding (router) status, collected by `os` only (costly on Windows);
+    /// None where it cannot be determined
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ipv4_forwarding: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ipv6_forwarding: Option<bool>,
+    /// Open sockets and conntrack entries, collected with `os --sockets`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sockets: Option<SocketCounts>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Socket counts across IPv4 and IPv6; None where the platform does not report them
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SocketCounts {
+    pub tcp: Option<u64>,
+    pub tcp_time_wait: Option<u64>,
+    pub udp: Option<u64>,
+    /// Tracked connections and the table limit (Linux netfilter)
+    pub conntrack: Option<u64>,
+    pub conntrack_max: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ProxyEnv {
     pub http: Option<String>,
     pub https: Option<String>,
@@ -33,14 +63,8 @@ pub fn system_info() -> SysInfo {
     let info = os_info::get();
     let os_type = info.os_type().to_string();
     let os_version = info.version().to_string();
-    let edition = info
-        .edition()
-        .unwrap_or_else(|| "unknown".into())
-        .to_string();
-    let codename = info
-        .codename()
-        .unwrap_or_else(|| "unknown".into())
-        .to_string();
+    let edition = info.edition().unwrap_or("unknown").to_string();
+    let codename = info.codename().unwrap_or("unknown").to_string();
     let bitness = if cfg!(target_pointer_width = "64") {
         "64-bit"
     } else {
@@ -53,6 +77,12 @@ pub fn system_info() -> SysInfo {
 
     let kernel_version = kernel_version();
 
+    let boot = boot_time();
+    let uptime_secs = boot
+        .and_then(|t| SystemTime::now().duration_since(t).ok())
+        .map(|d| d.as_secs());
+    let boot_time = boot.map(|t| humantime::format_rfc3339_seconds(t).to_string());
+
     SysInfo {
         hostname,
         os_type,
@@ -63,6 +93,11 @@ pub fn system_info() -> SysInfo {
         bitness,
         architecture,
         proxy,
+        boot_time,
+        uptime_secs,
+        ipv4_forwarding: None,
+        ipv6_forwarding: None,
+        sockets: None,
     }
 }
 
@@ -137,3 +172,187 @@ fn kernel_version() -> Option<String> {
 fn kernel_version() -> Option<String> {
     None
 }
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: get boot time from `btime` in /proc/stat
+fn boot_time() -> Option<SystemTime> {
+    let contents = std::fs::read_to_string("/proc/stat").ok()?;
+    let secs = contents
+        .lines()
+        .find_map(|line| line.strip_prefix("btime "))?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(UNIX_EPOCH + Duration::from_secs(secs))
+}
+
+#[cfg(target_os = "macos")]
+/// macOS-specific: get boot time using `sysctl kern.boottime`
+fn boot_time() -> Option<SystemTime> {
+    let mut mib = [libc::CTL_KERN, libc::KERN_BOOTTIME];
+    let mut tv: libc::timeval = unsafe { std::mem::zeroed() };
+    let mut len = std::mem::size_of::<libc::timeval>();
+    let ret = unsafe {
+        libc::sysctl(
+            mib.as_mut_ptr(),
+            mib.len() as u32,
+            &mut tv as *mut _ as *mut libc::c_void,
+            &mut len,
+            std::ptr::null_mut(),
+            0,
+        )
+    };
+    if ret != 0 || tv.tv_sec <= 0 {
+        return None;
+    }
+    Some(UNIX_EPOCH + Duration::from_secs(tv.tv_sec as u64))
+}
+
+#[cfg(target_os = "windows")]
+/// Windows-specific: derive boot time from `GetTickCount64`
+fn boot_time() -> Option<SystemTime> {
+    use windows_sys::Win32::System::SystemInformation::GetTickCount64;
+    let uptime = Duration::from_millis(unsafe { GetTickCount64() });
+    SystemTime::now().checked_sub(uptime)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn boot_time() -> Option<SystemTime> {
+    None
+}
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: read the IPv4/IPv6 forwarding sysctls from /proc/sys
+pub fn ip_forwarding() -> (Option<bool>, Option<bool>) {
+    let read =
+        |path: &str| -> Option<bool> { Some(std::fs::read_to_string(path).ok()?.trim() != "0") };
+    (
+        read("/proc/sys/net/ipv4/ip_forward"),
+        read("/proc/sys/net/ipv6/conf/all/forwarding"),
+    )
+}
+
+#[cfg(target_os = "macos")]
+/// macOS-specific: read `net.inet.ip.forwarding` / `net.inet6.ip6.forwarding`
+pub fn ip_forwarding() -> (Option<bool>, Option<bool>) {
+    fn sysctl_int(name: &std::ffi::CStr) -> Option<bool> {
+        let mut value: libc::c_int = 0;
+        let mut len = std::mem::size_of::<libc::c_int>();
+        let ret = unsafe {
+            libc::sysctlbyname(
+                name.as_ptr(),
+                &mut value as *mut _ as *mut libc::c_void,
+                &mut len,
+                std::ptr::null_mut(),
+                0,
+            )
+        };
+        (ret == 0).then_some(value != 0)
+    }
+    (
+        sysctl_int(c"net.inet.ip.forwarding"),
+        sysctl_int(c"net.inet6.ip6.forwarding"),
+    )
+}
+
+#[cfg(target_os = "windows")]
+/// Windows-specific: forwarding is on if any interface of the family has it enabled
+pub fn ip_forwarding() -> (Option<bool>, Option<bool>) {
+    let enabled = |family: &str| -> Option<bool> {
+        let out = std::process::Command::new("powershell")
+            .args([
+                "-NoProfile",
+                "-Command",
+                &format!(
+                    "@(Get-NetIPInterface -AddressFamily {} -Forwarding Enabled -ErrorAction SilentlyContinue).Count",
+                    family
+                ),
+            ])
+            .output()
+            .ok()?;
+        if !out.status.success() {
+            return None;
+        }
+        let count: u32 = String::from_utf8_lossy(&out.stdout).trim().parse().ok()?;
+        Some(count > 0)
+    };
+    (enabled("IPv4"), enabled("IPv6"))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+pub fn ip_forwarding() -> (Option<bool>, Option<bool>) {
+    (None, None)
+}
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: `/proc/net/sockstat{,6}` and the nf_conntrack sysctls
+/// (conntrack stays None when the module is not loaded)
+pub fn socket_counts() -> SocketCounts {
+    // (protocol, key, value), e.g. "TCP: inuse 4 orphan 0 tw 0 alloc 4 mem 0"
+    let mut stats: Vec<(String, String, u64)> = Vec::new();
+    for path in ["/proc/net/sockstat", "/proc/net/sockstat6"] {
+        let Ok(text) = std::fs::read_to_string(path) else {
+            continue;
+        };
+        for line in text.lines() {
+            let Some((proto, rest)) = line.split_once(':') else {
+                continue;
+            };
+            let fields: Vec<&str> = rest.split_whitespace().collect();
+            for kv in fields.chunks_exact(2) {
+                if let Ok(value) = kv[1].parse() {
+                    stats.push((proto.to_string(), kv[0].to_string(), value));
+                }
+            }
+        }
+    }
+    let sum = |protos: &[&str], key: &str| -> Option<u64> {
+        let mut found = stats
+            .iter()
+            .filter(|(p, k, _)| protos.contains(&p.as_str()) && k == key)
+            .peekable();
+        found.peek()?;
+        Some(found.map(|(_, _, v)| v).sum())
+    };
+    let read =
+        |path: &str| -> Option<u64> { std::fs::read_to_string(path).ok()?.trim().parse().ok() };
+    SocketCounts {
+        tcp: sum(&["TCP", "TCP6"], "inuse"),
+        tcp_time_wait: sum(&["TCP"], "tw"),
+        udp: sum(&["UDP", "UDP6"], "inuse"),
+        conntrack: read("/proc/sys/net/netfilter/nf_conntrack_count"),
+        conntrack_max: read("/proc/sys/net/netfilter/nf_conntrack_max"),
+    }
+}
+
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+/// Count the socket lines of `netstat -an` (no connection tracking)
+pub fn socket_counts() -> SocketCounts {
+    let Some(out) = std::process::Command::new("netstat")
+        .arg("-an")
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+    else {
+        return SocketCounts::default();
+    };
+    let text = String::from_utf8_lossy(&out.stdout);
+    let count = |proto: &str, state: Option<&str>| -> u64 {
+        text.lines()
+            .filter(|line| line.trim_start().to_ascii_lowercase().starts_with(proto))
+            .filter(|line| state.is_none_or(|s| line.contains(s)))
+            .count() as u64
+    };
+    SocketCounts {
+        tcp: Some(count("tcp", None)),
+        tcp_time_wait: Some(count("tcp", Some("TIME_WAIT"))),
+        udp: Some(count("udp", None)),
+        conntrack: None,
+        conntrack_max: None,
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+pub fn socket_counts() -> SocketCounts {
+    SocketCounts::default()
+}
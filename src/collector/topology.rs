@@ -0,0 +1,58 @@
+use netdev::Interface;
+
+use crate::model::topology::LinkTopology;
+
+/// Collect bridge/bond master-member relationships for the given interfaces
+pub fn collect_topology(ifaces: &[Interface]) -> Vec<LinkTopology> {
+    let mut links: Vec<LinkTopology> = ifaces
+        .iter()
+        .map(|iface| LinkTopology {
+            name: iface.name.clone(),
+            kind: link_kind(&iface.name),
+            master: link_master(&iface.name),
+            members: Vec::new(),
+        })
+        .collect();
+
+    // Members are derived from the master side of each link
+    let pairs: Vec<(String, String)> = links
+        .iter()
+        .filter_map(|l| l.master.clone().map(|m| (m, l.name.clone())))
+        .collect();
+    for (master, member) in pairs {
+        if let Some(link) = links.iter_mut().find(|l| l.name == master) {
+            link.members.push(member);
+        }
+    }
+    links
+}
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: resolve `/sys/class/net/<if>/master`
+fn link_master(name: &str) -> Option<String> {
+    let target = std::fs::read_link(format!("/sys/class/net/{}/master", name)).ok()?;
+    target.file_name().map(|n| n.to_string_lossy().into_owned())
+}
+
+#[cfg(target_os = "linux")]
+/// Linux-specific: detect bridge/bond from sysfs
+fn link_kind(name: &str) -> Option<String> {
+    let base = std::path::Path::new("/sys/class/net").join(name);
+    if base.join("bridge").is_dir() {
+        Some("bridge".into())
+    } else if base.join("bonding").is_dir() {
+        Some("bond".into())
+    } else {
+        None
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn link_master(_name: &str) -> Option<String> {
+    None
+}
+
+#[cfg(not(target_os = "linux"))]
+fn link_kind(_name: &str) -> Option<String> {
+    None
+}
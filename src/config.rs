@@ -0,0 +1,97 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+pub static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Environment variable overriding the config file location
+pub const CONFIG_ENV: &str = "NIFA_CONFIG";
+
+/// User configuration (config.toml)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Display aliases (real interface name -> display name)
+    pub aliases: BTreeMap<String, String>,
+    /// Custom public IP providers (name -> spec), used by `public --provider`
+    pub providers: BTreeMap<String, ProviderSpec>,
+    /// Interfaces expected to run in promiscuous mode (not reported by `check`)
+    pub promiscuous_allowed: Vec<String>,
+    /// Config file this was loaded from
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+}
+
+/// Custom ip-info endpoint
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProviderSpec {
+    pub url: String,
+    /// `IpInfo` field -> dotted JSON key path in the response (e.g. `ip_addr = "ip"`,
+    /// `country_code = "location.country"`). Empty: the response is parsed as `IpInfo` directly.
+    #[serde(default)]
+    pub mapping: BTreeMap<String, String>,
+}
+
+/// Resolve the config file path
+/// `$NIFA_CONFIG`, then `$XDG_CONFIG_HOME/nifa/config.toml` or `~/.config/nifa/config.toml`
+/// (Windows: `%APPDATA%\nifa\config.toml`)
+pub fn config_path() -> Option<PathBuf> {
+    if let Some(p) = std::env::var_os(CONFIG_ENV) {
+        return Some(PathBuf::from(p));
+    }
+    let base = if cfg!(windows) {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))
+    };
+    base.map(|b| b.join("nifa").join("config.toml"))
+}
+
+/// Load the config file (defaults if it does not exist)
+pub fn load_config() -> Result<Config> {
+    let Some(path) = config_path().filter(|p| p.is_file()) else {
+        return Ok(Config::default());
+    };
+    let text =
+        std::fs::read_to_string(&path).with_context(|| format!("read {}", path.display()))?;
+    let mut config: Config =
+        toml::from_str(&text).with_context(|| format!("parse {}", path.display()))?;
+    config.path = Some(path);
+    Ok(config)
+}
+
+/// Initialize global config
+pub fn init_config() -> Result<()> {
+    let config = load_config()?;
+    CONFIG
+        .set(config)
+        .map_err(|_| anyhow::anyhow!("Failed to set CONFIG in OnceLock"))?;
+    Ok(())
+}
+
+/// Get reference to global config (defaults if not initialized)
+pub fn config() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+/// Get the configured alias for an interface name
+pub fn alias_of(name: &str) -> Option<&'static str> {
+    config().aliases.get(name).map(|s| s.as_str())
+}
+
+/// Get a configured public IP provider by name
+pub fn provider(name: &str) -> Option<&'static ProviderSpec> {
+    config().providers.get(name)
+}
+
+/// Interface name for display: `alias (name)` when an alias is configured
+pub fn display_name(name: &str) -> String {
+    match alias_of(name) {
+        Some(alias) => format!("{} ({})", alias, name),
+        None => name.to_string(),
+    }
+}
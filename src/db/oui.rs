@@ -1,5 +1,6 @@
 use anyhow::Result;
 use ndb_oui::OuiDb;
+use netdev::MacAddr;
 use std::sync::OnceLock;
 
 pub static OUI_DB: OnceLock<OuiDb> = OnceLock::new();
@@ -22,3 +23,17 @@ pub fn oui_db() -> &'static OuiDb {
 pub fn is_oui_db_initialized() -> bool {
     OUI_DB.get().is_some()
 }
+
+/// Vendor name for a MAC address (None if the database is not loaded or has no match)
+pub fn lookup_vendor(mac: &MacAddr) -> Option<String> {
+    if !is_oui_db_initialized() || *mac == MacAddr::zero() {
+        return None;
+    }
+    let vendor = oui_db().lookup_mac(mac)?;
+    Some(
+        vendor
+            .vendor_detail
+            .clone()
+            .unwrap_or(vendor.vendor.clone()),
+    )
+}
@@ -3,41 +3,114 @@ use clap::Parser;
 mod cli;
 mod cmd;
 mod collector;
+mod config;
 mod db;
 mod model;
 mod renderer;
 
-use cli::{Cli, Command};
+use cli::{Cli, Command, OutputFormat};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+    match run(&cli).await {
+        Ok(()) => Ok(()),
+        // The command already reported the outcome; only the status is left
+        Err(e) if e.is::<cmd::ExitStatus>() => {
+            let code = e
+                .downcast_ref::<cmd::ExitStatus>()
+                .map_or(1, |status| status.0);
+            std::process::exit(code)
+        }
+        Err(e) => match cli.format {
+            // Default anyhow printout for humans
+            OutputFormat::Tree => Err(e),
+            format => {
+                print_error(format, &e);
+                std::process::exit(1);
+            }
+        },
+    }
+}
+
+/// Render a top-level error as `{"error": "..."}` in the selected format
+fn print_error(format: OutputFormat, err: &anyhow::Error) {
+    let out = serde_json::json!({ "error": format!("{:#}", err) });
+    match format {
+        OutputFormat::Yaml => print!("{}", serde_yaml::to_string(&out).unwrap_or_default()),
+        _ => println!("{}", out),
+    }
+}
+
+async fn run(cli: &Cli) -> Result<()> {
+    config::init_config()?;
 
     if cli.with_vendor {
         db::oui::init_oui_db()?;
     }
 
+    if cli.debug_dump {
+        collector::iface::dump_raw_interfaces();
+    }
+
+    // --template renders a snapshot, which only these commands produce
+    if cli.template.is_some()
+        && !matches!(
+            cli.command,
+            None | Some(Command::List(_) | Command::Show(_) | Command::Os(_) | Command::Export(_))
+        )
+    {
+        anyhow::bail!("--template is only supported by list, show, os and export");
+    }
+
     match &cli.command {
+        None if cli.template.is_some() => {
+            cmd::export::print_template(cli)?;
+        }
         None => {
-            cmd::list::show_interfaces(&cli);
+            cmd::list::show_interfaces(cli).await;
         }
         Some(Command::List(args)) => {
-            cmd::list::list_interfaces(&cli, args);
+            cmd::list::list_interfaces(cli, args)?;
         }
         Some(Command::Show(args)) => {
-            cmd::show::show_interface(&cli, args);
+            cmd::show::show_interface(cli, args)?;
+        }
+        Some(Command::Os(args)) => {
+            cmd::os::show_system_net_stack(cli, args).await?;
+        }
+        Some(Command::Check(args)) => {
+            cmd::check::run_checks(cli, args)?;
+        }
+        Some(Command::Arp(args)) => {
+            cmd::arp::show_neighbors(cli, args)?;
         }
-        Some(Command::Os) => {
-            cmd::os::show_system_net_stack(&cli);
+        Some(Command::Config) => {
+            cmd::config::show_config(cli)?;
         }
         Some(Command::Export(args)) => {
-            cmd::export::export_snapshot(&cli, args)?;
+            cmd::export::export_snapshot(cli, args)?;
         }
-        Some(Command::Monitor(args)) => {
-            cmd::monitor::monitor_interfaces(&cli, args)?;
+        Some(Command::Monitor(args)) | Some(Command::Top(args)) => {
+            cmd::monitor::monitor_interfaces(cli, args)?;
+        }
+        Some(Command::Validate(args)) => {
+            cmd::validate::validate_interfaces(cli, args)?;
         }
         Some(Command::Public(args)) => {
-            cmd::public::show_public_ip_info(&cli, args).await?;
+            cmd::public::show_public_ip_info(cli, args).await?;
+        }
+        Some(Command::Vpn(args)) => {
+            cmd::vpn::show_vpn_heuristic(cli, args)?;
+        }
+        Some(Command::WaitOnline(args)) => {
+            cmd::wait::wait_online(cli, args)?;
+        }
+        Some(Command::Up(args)) => {
+            cmd::admin::set_interface_state(cli, args, true)?;
+        }
+        Some(Command::Down(args)) => {
+            cmd::admin::set_interface_state(cli, args, false)?;
         }
     };
     Ok(())
@@ -0,0 +1,50 @@
+use std::net::{IpAddr, Ipv6Addr};
+
+use serde::{Deserialize, Serialize};
+
+/// Preferred/valid lifetimes of an IPv6 address (SLAAC/DHCPv6)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ipv6Lifetime {
+    pub iface: String,
+    pub addr: Ipv6Addr,
+    /// Remaining preferred lifetime in seconds (`None` = forever)
+    pub preferred_secs: Option<u64>,
+    /// Remaining valid lifetime in seconds (`None` = forever)
+    pub valid_secs: Option<u64>,
+}
+
+/// IPv6 router advertisement state of an interface
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ipv6RaInfo {
+    pub iface: String,
+    /// Whether a router advertisement has been received
+    pub received: bool,
+    /// M flag: addresses are assigned via DHCPv6
+    pub managed: bool,
+    /// O flag: other configuration (DNS, ...) via DHCPv6
+    pub other_config: bool,
+    /// Remaining default router lifetime in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub router_lifetime_secs: Option<u64>,
+}
+
+impl Ipv6RaInfo {
+    /// Address configuration mode implied by the flags
+    pub fn mode(&self) -> &'static str {
+        match (self.received, self.managed, self.other_config) {
+            (false, _, _) => "no RA received",
+            (true, true, _) => "DHCPv6 (stateful)",
+            (true, false, true) => "SLAAC + DHCPv6 (stateless)",
+            (true, false, false) => "SLAAC",
+        }
+    }
+}
+
+/// Multicast group joined by an interface
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MulticastGroup {
+    pub iface: String,
+    pub group: IpAddr,
+    /// Number of sockets/users holding the membership
+    pub users: u32,
+}
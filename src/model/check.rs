@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Ok,
+    Warn,
+    Fail,
+}
+
+/// Result of a single diagnostic rule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Finding {
+    pub level: Level,
+    pub rule: String,
+    pub message: String,
+}
+
+impl Finding {
+    pub fn ok<S: Into<String>>(rule: &str, message: S) -> Self {
+        Finding {
+            level: Level::Ok,
+            rule: rule.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn warn<S: Into<String>>(rule: &str, message: S) -> Self {
+        Finding {
+            level: Level::Warn,
+            rule: rule.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn fail<S: Into<String>>(rule: &str, message: S) -> Self {
+        Finding {
+            level: Level::Fail,
+            rule: rule.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckReport {
+    pub findings: Vec<Finding>,
+}
+
+impl CheckReport {
+    pub fn warnings(&self) -> usize {
+        self.findings
+            .iter()
+            .filter(|f| f.level == Level::Warn)
+            .count()
+    }
+
+    pub fn failures(&self) -> usize {
+        self.findings
+            .iter()
+            .filter(|f| f.level == Level::Fail)
+            .count()
+    }
+}
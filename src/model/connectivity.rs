@@ -0,0 +1,56 @@
+use std::fmt;
+use std::net::SocketAddr;
+
+use serde::{Deserialize, Serialize};
+
+/// Overall verdict of the connectivity probes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Verdict {
+    /// Every target answered
+    Ok,
+    /// Some targets answered
+    Degraded,
+    /// No target answered
+    Down,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Ok => write!(f, "OK"),
+            Verdict::Degraded => write!(f, "Degraded"),
+            Verdict::Down => write!(f, "Down"),
+        }
+    }
+}
+
+/// Result of a single TCP connect probe
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TcpProbe {
+    pub target: SocketAddr,
+    pub reachable: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+}
+
+/// Quick internet connectivity summary (`--probe`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Connectivity {
+    pub verdict: Verdict,
+    pub probes: Vec<TcpProbe>,
+}
+
+impl Connectivity {
+    pub fn from_probes(probes: Vec<TcpProbe>) -> Self {
+        let up = probes.iter().filter(|p| p.reachable).count();
+        let verdict = if up == 0 {
+            Verdict::Down
+        } else if up == probes.len() {
+            Verdict::Ok
+        } else {
+            Verdict::Degraded
+        };
+        Connectivity { verdict, probes }
+    }
+}
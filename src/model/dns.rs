@@ -0,0 +1,36 @@
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+
+/// Heuristic about encrypted DNS (DoH/DoT) usage
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DohHeuristic {
+    /// Configured resolvers (interface DNS servers and resolv.conf)
+    pub resolvers: Vec<IpAddr>,
+    /// Resolvers point at a loopback stub (e.g. systemd-resolved, dnscrypt-proxy)
+    pub stub_resolver: bool,
+    /// DNS-over-TLS is enabled in the local resolver configuration
+    pub dot_configured: bool,
+    /// A non-systemd loopback stub usually forwards over DoH
+    pub doh_likely: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub probes: Vec<DohProbe>,
+}
+
+/// Reachability of a public DoH endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DohProbe {
+    pub url: String,
+    pub reachable: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// DNS search domains (suffixes) associated with an interface
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterfaceSearchDomains {
+    pub iface: String,
+    pub domains: Vec<String>,
+}
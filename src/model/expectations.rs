@@ -0,0 +1,30 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Declarative spec of the expected interface configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Expectations {
+    /// Interface name -> expected fields
+    pub interfaces: BTreeMap<String, InterfaceExpectation>,
+}
+
+/// Expected fields of a single interface (omitted fields are not checked)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InterfaceExpectation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtu: Option<u32>,
+    /// Operational state, e.g. "up" or "down" (case-insensitive)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// Addresses that must be present ("addr" or "addr/prefix")
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ipv4: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ipv6: Vec<String>,
+    /// Gateway IP address that must be present
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gateway: Option<String>,
+}
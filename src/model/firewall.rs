@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// Firewall zone (firewalld) or network profile (Windows) assigned to an interface
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterfaceZone {
+    pub iface: String,
+    /// "zone" (firewalld) or "profile" (Windows network category)
+    pub kind: String,
+    /// e.g. "public", "Private"
+    pub name: String,
+}
+
+impl InterfaceZone {
+    /// Tree label, e.g. "Zone: public" / "Profile: Private"
+    pub fn label(&self) -> String {
+        let kind = match self.kind.as_str() {
+            "profile" => "Profile",
+            _ => "Zone",
+        };
+        format!("{}: {}", kind, self.name)
+    }
+}
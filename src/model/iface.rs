@@ -0,0 +1,61 @@
+use netdev::Interface;
+use serde::{Deserialize, Serialize};
+
+/// Interface plus data resolved by nifa (serialized flat, used with `--with-vendor`)
+#[derive(Debug, Clone, Serialize)]
+pub struct InterfaceView {
+    #[serde(flatten)]
+    pub iface: Interface,
+    /// OUI vendor of the MAC address
+    pub vendor: Option<String>,
+}
+
+impl InterfaceView {
+    pub fn new(iface: &Interface) -> Self {
+        InterfaceView {
+            vendor: iface
+                .mac_addr
+                .as_ref()
+                .and_then(crate::db::oui::lookup_vendor),
+            iface: iface.clone(),
+        }
+    }
+}
+
+/// How an interface differs from a reference snapshot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Changed,
+    New,
+    Removed,
+}
+
+impl ChangeKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            ChangeKind::Changed => "changed",
+            ChangeKind::New => "new",
+            ChangeKind::Removed => "removed",
+        }
+    }
+}
+
+/// Interface reported by `list --changed-since` (removed ones come from the snapshot)
+#[derive(Debug, Clone, Serialize)]
+pub struct InterfaceChange {
+    pub change: ChangeKind,
+    #[serde(flatten)]
+    pub iface: Interface,
+}
+
+/// Duplex and autonegotiation of a physical link
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkMode {
+    pub iface: String,
+    /// "full" or "half"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duplex: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub autoneg: Option<bool>,
+}
@@ -13,14 +13,14 @@ pub struct IpInfo {
     pub country_name: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PublicOut {
     pub common: Option<CommonInfo>,
     pub ipv4: Option<IpSide>,
     pub ipv6: Option<IpSide>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CommonInfo {
     pub asn: String,
     pub as_name: String,
@@ -28,18 +28,99 @@ pub struct CommonInfo {
     pub country_name: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct IpSide {
     pub ip_addr: String,
     pub ip_addr_dec: String,
     pub host_name: String,
     pub network: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub asn: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub as_name: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub country_code: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub country_name: Option<String>,
 }
+
+/// One line of the `public --history` JSON-lines file
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PublicHistoryEntry {
+    /// RFC 3339 timestamp of the lookup
+    pub ts: String,
+    #[serde(flatten)]
+    pub out: PublicOut,
+}
+
+impl PublicHistoryEntry {
+    /// Whether the public addresses differ from `other`
+    pub fn addresses_differ(&self, other: &PublicHistoryEntry) -> bool {
+        let addr = |side: &Option<IpSide>| side.as_ref().map(|s| s.ip_addr.clone());
+        addr(&self.out.ipv4) != addr(&other.out.ipv4)
+            || addr(&self.out.ipv6) != addr(&other.out.ipv6)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn side(ip: &str) -> IpSide {
+        IpSide {
+            ip_addr: ip.into(),
+            ip_addr_dec: "3405803781".into(),
+            host_name: "host.example.net".into(),
+            network: "203.0.113.0/24".into(),
+            asn: Some("64500".into()),
+            as_name: Some("EXAMPLE-AS".into()),
+            country_code: Some("JP".into()),
+            country_name: None,
+        }
+    }
+
+    #[test]
+    fn public_out_round_trips_with_common_none() {
+        let out = PublicOut {
+            common: None,
+            ipv4: Some(side("203.0.113.5")),
+            ipv6: None,
+        };
+        let json = serde_json::to_value(&out).unwrap();
+        assert!(json["common"].is_null());
+        assert!(json["ipv6"].is_null());
+        // Unset per-side fields are omitted, not null
+        assert!(json["ipv4"].get("country_name").is_none());
+
+        let back: PublicOut = serde_json::from_value(json.clone()).unwrap();
+        assert!(back.common.is_none());
+        assert!(back.ipv6.is_none());
+        let v4 = back.ipv4.as_ref().unwrap();
+        assert_eq!(v4.ip_addr, "203.0.113.5");
+        assert_eq!(v4.asn.as_deref(), Some("64500"));
+        assert_eq!(v4.country_name, None);
+        assert_eq!(serde_json::to_value(&back).unwrap(), json);
+    }
+
+    #[test]
+    fn history_entry_round_trips() {
+        let entry = PublicHistoryEntry {
+            ts: "2026-10-17T00:00:00Z".into(),
+            out: PublicOut {
+                common: Some(CommonInfo {
+                    asn: "64500".into(),
+                    as_name: "EXAMPLE-AS".into(),
+                    country_code: "JP".into(),
+                    country_name: "Japan".into(),
+                }),
+                ipv4: Some(side("203.0.113.5")),
+                ipv6: None,
+            },
+        };
+        let line = serde_json::to_string(&entry).unwrap();
+        let back: PublicHistoryEntry = serde_json::from_str(&line).unwrap();
+        assert_eq!(back.ts, entry.ts);
+        assert_eq!(back.out.common.as_ref().unwrap().asn, "64500");
+        assert!(!back.addresses_differ(&entry));
+    }
+}
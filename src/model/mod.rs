@@ -1,2 +1,29 @@
+pub mod addr;
+pub mod check;
+pub mod connectivity;
+pub mod dns;
+pub mod expectations;
+pub mod firewall;
+pub mod iface;
 pub mod ipinfo;
+pub mod neighbor;
 pub mod snapshot;
+pub mod topology;
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+
+/// Read a JSON (`.json`) or YAML (any other extension) file
+pub fn load_json_or_yaml<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let is_json = path.extension().is_some_and(|e| e == "json");
+    let value = if is_json {
+        serde_json::from_str(&text).map_err(anyhow::Error::from)
+    } else {
+        serde_yaml::from_str(&text).map_err(anyhow::Error::from)
+    };
+    value.with_context(|| format!("Malformed {}", path.display()))
+}
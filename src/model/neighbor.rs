@@ -0,0 +1,18 @@
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+
+/// Entry of the ARP/NDP neighbor cache
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeighborEntry {
+    pub ip: IpAddr,
+    pub mac: String,
+    /// Interface the neighbor was learned on
+    pub iface: String,
+    /// Kernel reachability state (e.g. "REACHABLE", "STALE") when known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// Seconds since the entry last changed state, when the OS exposes it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub age_secs: Option<u64>,
+}
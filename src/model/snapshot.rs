@@ -1,10 +1,204 @@
+use std::path::Path;
+
+use anyhow::Result;
 use netdev::Interface;
 use serde::{Deserialize, Serialize};
 
+use crate::collector::IfaceLookups;
+use crate::collector::dhcp::DhcpInfo;
 use crate::collector::sys::SysInfo;
+use crate::model::addr::{Ipv6Lifetime, Ipv6RaInfo, MulticastGroup};
+use crate::model::dns::{DohHeuristic, InterfaceSearchDomains};
+use crate::model::firewall::InterfaceZone;
+use crate::model::iface::LinkMode;
+
+/// Interface stats are raw OS counters accumulated since boot
+pub const STATS_BASIS_SINCE_BOOT: &str = "cumulative_since_boot";
+
+fn default_stats_basis() -> String {
+    STATS_BASIS_SINCE_BOOT.to_string()
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Snapshot {
+    #[serde(default)]
     pub sys: SysInfo,
+    /// How to interpret `interfaces[].stats` (see `sys.boot_time` to normalize)
+    #[serde(default = "default_stats_basis")]
+    pub stats_basis: String,
+    #[serde(default)]
     pub interfaces: Vec<Interface>,
+    /// IPv6 preferred/valid lifetimes of the interfaces' addresses, where available
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ipv6_lifetimes: Vec<Ipv6Lifetime>,
+    /// IPv6 router advertisement flags of interfaces that received an RA, where available
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ipv6_ra: Vec<Ipv6RaInfo>,
+    /// Firewall zone / network profile per interface, where available
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub zones: Vec<InterfaceZone>,
+    /// Multicast group memberships, where available
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub multicast_groups: Vec<MulticastGroup>,
+    /// DNS search domains per interface, where available
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub search_domains: Vec<InterfaceSearchDomains>,
+    /// Duplex/autonegotiation of physical links, where available
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub link_modes: Vec<LinkMode>,
+}
+
+impl Snapshot {
+    /// Load a snapshot written by `export` (`.json` as JSON, anything else as YAML)
+    pub fn from_path(path: &Path) -> Result<Snapshot> {
+        crate::model::load_json_or_yaml(path)
+    }
+
+    /// Snapshot of `interfaces` with the lookup data that belongs to them
+    pub fn new(sys: SysInfo, interfaces: Vec<Interface>, lookups: &IfaceLookups) -> Self {
+        let ipv6_lifetimes = lookups
+            .ipv6_lifetimes
+            .iter()
+            .filter(|lt| interfaces.iter().any(|i| i.name == lt.iface))
+            .cloned()
+            .collect();
+        let ipv6_ra = interfaces
+            .iter()
+            .filter_map(|i| lookups.ra_of(i))
+            .filter(|ra| ra.received)
+            .cloned()
+            .collect();
+        let zones = interfaces
+            .iter()
+            .filter_map(|i| lookups.zone_of(i))
+            .cloned()
+            .collect();
+        let multicast_groups = interfaces
+            .iter()
+            .flat_map(|i| lookups.multicast_groups_of(i))
+            .cloned()
+            .collect();
+        let search_domains = lookups
+            .search_domains
+            .iter()
+            .filter(|d| {
+                interfaces
+                    .iter()
+                    .any(|i| i.name == d.iface || i.friendly_name.as_deref() == Some(&d.iface))
+            })
+            .cloned()
+            .collect();
+        let link_modes = interfaces
+            .iter()
+            .filter_map(|i| lookups.link_mode_of(i))
+            .cloned()
+            .collect();
+        Snapshot {
+            sys,
+            stats_basis: default_stats_basis(),
+            interfaces,
+            ipv6_lifetimes,
+            ipv6_ra,
+            zones,
+            multicast_groups,
+            search_domains,
+            link_modes,
+        }
+    }
+}
+
+/// JSON/YAML output of `os`: the snapshot of the default interface plus the
+/// system-wide details the tree shows
+#[derive(Debug, Serialize)]
+pub struct OsReport {
+    #[serde(flatten)]
+    pub snapshot: Snapshot,
+    /// DHCP lease of the default interface
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dhcp: Option<DhcpInfo>,
+    /// Encrypted DNS heuristic, with endpoint probes under `--check-doh`
+    pub doh: DohHeuristic,
+    /// Every raw os_info field, with `--verbose-os`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub os_info: Option<os_info::Info>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Write `text` to a unique temp file with the given extension
+    fn temp_file(name: &str, text: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("nifa-test-{}-{}", std::process::id(), name));
+        std::fs::write(&path, text).unwrap();
+        path
+    }
+
+    #[test]
+    fn loads_baseline_format_json() {
+        // Shape written before stats_basis and the per-interface extension lists existed
+        let path = temp_file(
+            "old.json",
+            r#"{
+  "sys": {
+    "hostname": "host1",
+    "os_type": "Ubuntu",
+    "os_version": "22.04",
+    "kernel_version": "5.15.0",
+    "edition": "Unknown",
+    "codename": "jammy",
+    "bitness": "64-bit",
+    "architecture": "x86_64",
+    "proxy": {"http": null, "https": null, "all": null, "no_proxy": null}
+  },
+  "interfaces": [
+    {
+      "index": 2,
+      "name": "eth0",
+      "friendly_name": null,
+      "description": null,
+      "if_type": "Ethernet",
+      "mac_addr": "52:54:00:12:34:56",
+      "ipv4": ["192.168.1.5/24"],
+      "ipv6": [],
+      "ipv6_scope_ids": [],
+      "flags": 69699,
+      "oper_state": "Up",
+      "transmit_speed": null,
+      "receive_speed": null,
+      "stats": null,
+      "gateway": null,
+      "dns_servers": [],
+      "mtu": 1500,
+      "default": true
+    }
+  ]
+}"#,
+        );
+        let snapshot = Snapshot::from_path(&path);
+        std::fs::remove_file(&path).unwrap();
+        let snapshot = snapshot.unwrap();
+
+        assert_eq!(snapshot.sys.hostname, "host1");
+        assert_eq!(snapshot.sys.boot_time, None);
+        assert_eq!(snapshot.stats_basis, STATS_BASIS_SINCE_BOOT);
+        assert_eq!(snapshot.interfaces.len(), 1);
+        assert_eq!(snapshot.interfaces[0].name, "eth0");
+        assert!(snapshot.ipv6_lifetimes.is_empty());
+        assert!(snapshot.ipv6_ra.is_empty());
+        assert!(snapshot.zones.is_empty());
+        assert!(snapshot.multicast_groups.is_empty());
+        assert!(snapshot.search_domains.is_empty());
+        assert!(snapshot.link_modes.is_empty());
+    }
+
+    #[test]
+    fn malformed_json_names_the_file() {
+        let path = temp_file("broken.json", r#"{"interfaces": [ {"#);
+        let err = Snapshot::from_path(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        let msg = format!("{:#}", err);
+        assert!(msg.starts_with("Malformed "), "{}", msg);
+        assert!(msg.contains("broken.json"), "{}", msg);
+    }
 }
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Master/member relationship of an interface (bridge, bond, ...)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LinkTopology {
+    pub name: String,
+    /// "bridge" or "bond" when this interface aggregates others
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Bridge/bond this interface is enslaved to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub master: Option<String>,
+    /// Interfaces enslaved to this one
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<String>,
+}
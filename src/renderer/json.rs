@@ -1,16 +1,233 @@
-use crate::{collector::sys::SysInfo, model::snapshot::Snapshot};
+use crate::{
+    cli::TableField, db::oui::is_oui_db_initialized, model::iface::InterfaceView,
+    model::snapshot::OsReport,
+};
+use clap::ValueEnum;
 use netdev::Interface;
+use serde::Serialize;
+use serde_json::Value;
+use std::io::IsTerminal;
 
-pub fn print_interface_json(ifaces: &[Interface]) {
-    let json = serde_json::to_string_pretty(ifaces).unwrap();
-    println!("{}", json);
+const KEY_COLOR: &str = "\x1b[1;34m";
+const STRING_COLOR: &str = "\x1b[32m";
+const NUMBER_COLOR: &str = "\x1b[33m";
+const LITERAL_COLOR: &str = "\x1b[35m";
+const RESET: &str = "\x1b[0m";
+
+pub fn print_interface_json(ifaces: &[Interface], color: bool) {
+    // With --with-vendor, each interface carries its resolved OUI vendor
+    if is_oui_db_initialized() {
+        let views: Vec<InterfaceView> = ifaces.iter().map(InterfaceView::new).collect();
+        print_json(&views, color).unwrap()
+    } else {
+        print_json(ifaces, color).unwrap()
+    }
+}
+
+pub fn print_os_report_json(report: &OsReport, color: bool) {
+    print_json(report, color).unwrap();
+}
+
+/// Print pretty JSON to stdout, highlighted when `color` is set and stdout is a terminal
+pub fn print_json<T: Serialize + ?Sized>(value: &T, color: bool) -> serde_json::Result<()> {
+    let json = serde_json::to_string_pretty(value)?;
+    if color && std::io::stdout().is_terminal() {
+        println!("{}", highlight(&json));
+    } else {
+        println!("{}", json);
+    }
+    Ok(())
+}
+
+/// Wrap keys, strings, numbers and true/false/null of serialized JSON in ANSI colors
+fn highlight(json: &str) -> String {
+    let mut out = String::with_capacity(json.len() * 2);
+    let mut chars = json.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let (end, color) = match c {
+            '"' => {
+                let mut end = json.len();
+                let mut escaped = false;
+                for (i, c) in chars.by_ref() {
+                    if escaped {
+                        escaped = false;
+                    } else if c == '\\' {
+                        escaped = true;
+                    } else if c == '"' {
+                        end = i + 1;
+                        break;
+                    }
+                }
+                // A string directly followed by ':' is an object key
+                let is_key = json[end..].trim_start().starts_with(':');
+                (end, if is_key { KEY_COLOR } else { STRING_COLOR })
+            }
+            '-' | '0'..='9' | 't' | 'f' | 'n' => {
+                let mut end = start + 1;
+                while let Some((i, c)) = chars
+                    .next_if(|(_, c)| c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-'))
+                {
+                    end = i + c.len_utf8();
+                }
+                let color = if c.is_ascii_alphabetic() {
+                    LITERAL_COLOR
+                } else {
+                    NUMBER_COLOR
+                };
+                (end, color)
+            }
+            _ => {
+                out.push(c);
+                continue;
+            }
+        };
+        out.push_str(color);
+        out.push_str(&json[start..end]);
+        out.push_str(RESET);
+    }
+    out
+}
+
+/// Scalars of a JSON value keyed by their dotted path (`interfaces.0.ipv4.0`),
+/// in document order. Array elements use their index; empty arrays/objects are dropped.
+/// `\\`, `.`, `=` and line breaks inside a key are backslash-escaped.
+pub fn flatten(value: &Value) -> Vec<(String, Value)> {
+    fn walk(value: &Value, path: &mut String, out: &mut Vec<(String, Value)>) {
+        let mut descend = |key: &str, child: &Value, path: &mut String| {
+            let len = path.len();
+            if !path.is_empty() {
+                path.push('.');
+            }
+            path.push_str(&escape_flat(key, &['.', '=']));
+            walk(child, path, out);
+            path.truncate(len);
+        };
+        match value {
+            Value::Object(map) => map.iter().for_each(|(k, v)| descend(k, v, path)),
+            Value::Array(items) => items
+                .iter()
+                .enumerate()
+                .for_each(|(i, v)| descend(&i.to_string(), v, path)),
+            scalar => out.push((path.clone(), scalar.clone())),
+        }
+    }
+    let mut out = Vec::new();
+    walk(value, &mut String::new(), &mut out);
+    out
+}
+
+/// Flattened value as a JSON object, one `"path": value` pair per line
+/// (written by hand so the document order survives)
+pub fn flat_json(value: &Value) -> String {
+    let lines: Vec<String> = flatten(value)
+        .into_iter()
+        .map(|(path, v)| format!("{}: {}", Value::from(path), v))
+        .collect();
+    format!("{{\n  {}\n}}\n", lines.join(",\n  "))
+}
+
+/// Flattened value as `path=value` lines; strings are written bare
+pub fn flat_kv(value: &Value) -> String {
+    flatten(value)
+        .into_iter()
+        .map(|(path, v)| match v {
+            Value::String(s) => format!("{}={}\n", path, escape_flat(&s, &[])),
+            other => format!("{}={}\n", path, other),
+        })
+        .collect()
+}
+
+/// Backslash-escape `\\`, line breaks and `special` so a flat path or value
+/// stays on one line and splits unambiguously
+fn escape_flat(s: &str, special: &[char]) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c if special.contains(&c) => {
+                out.push('\\');
+                out.push(c);
+            }
+            c => out.push(c),
+        }
+    }
+    out
 }
 
-pub fn print_snapshot_json(sys: &SysInfo, default_iface: Option<Interface>) {
-    let snapshot = Snapshot {
-        sys: sys.clone(),
-        interfaces: default_iface.into_iter().collect(),
-    };
-    let json = serde_json::to_string_pretty(&snapshot).unwrap();
-    println!("{}", json);
+/// Interfaces as an array of arrays: a header row of field names, then one
+/// row per interface. Lists are comma-joined, missing values are null.
+/// Each row is written on its own line.
+pub fn interface_table_json(ifaces: &[Interface], fields: &[TableField]) -> String {
+    let header: Vec<Value> = fields
+        .iter()
+        .map(|f| {
+            f.to_possible_value()
+                .map_or(Value::Null, |v| Value::from(v.get_name()))
+        })
+        .collect();
+    let rows = ifaces
+        .iter()
+        .map(|iface| Value::Array(fields.iter().map(|f| table_cell(iface, *f)).collect()));
+    let lines: Vec<String> = std::iter::once(Value::Array(header))
+        .chain(rows)
+        .map(|row| row.to_string())
+        .collect();
+    format!("[\n  {}\n]\n", lines.join(",\n  "))
+}
+
+fn table_cell(iface: &Interface, field: TableField) -> Value {
+    let join = |items: Vec<String>| Value::from(items.join(","));
+    match field {
+        TableField::Name => Value::from(iface.name.clone()),
+        TableField::Index => Value::from(iface.index),
+        TableField::Type => Value::from(format!("{:?}", iface.if_type)),
+        TableField::State => Value::from(format!("{:?}", iface.oper_state)),
+        TableField::Mac => iface
+            .mac_addr
+            .map_or(Value::Null, |m| Value::from(m.to_string())),
+        TableField::Mtu => iface.mtu.map_or(Value::Null, Value::from),
+        TableField::Ipv4 => join(iface.ipv4.iter().map(|n| n.to_string()).collect()),
+        TableField::Ipv6 => join(iface.ipv6.iter().map(|n| n.to_string()).collect()),
+        TableField::Gateway => iface.gateway.as_ref().map_or(Value::Null, |gw| {
+            join(
+                gw.ipv4
+                    .iter()
+                    .map(|ip| ip.to_string())
+                    .chain(gw.ipv6.iter().map(|ip| ip.to_string()))
+                    .collect(),
+            )
+        }),
+        TableField::Dns => join(iface.dns_servers.iter().map(|ip| ip.to_string()).collect()),
+        TableField::Default => Value::from(iface.default),
+        TableField::RxBytes => iface
+            .stats
+            .as_ref()
+            .map_or(Value::Null, |st| Value::from(st.rx_bytes)),
+        TableField::TxBytes => iface
+            .stats
+            .as_ref()
+            .map_or(Value::Null, |st| Value::from(st.tx_bytes)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flat_paths_and_values_are_escaped() {
+        let value = serde_json::json!({
+            "a.b": {"c=d": 1},
+            "a": {"b": [true]},
+            "note": "line one\nline two\\",
+        });
+        let paths: Vec<String> = flatten(&value).into_iter().map(|(p, _)| p).collect();
+        assert!(paths.contains(&"a\\.b.c\\=d".to_string()));
+        assert!(paths.contains(&"a.b.0".to_string()));
+        let kv = flat_kv(&value);
+        assert!(kv.contains("note=line one\\nline two\\\\\n"));
+        assert_eq!(kv.lines().count(), 3);
+    }
 }
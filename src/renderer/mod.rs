@@ -1,3 +1,5 @@
 pub mod json;
+pub mod plot;
+pub mod template;
 pub mod tree;
 pub mod yaml;
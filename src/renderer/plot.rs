@@ -0,0 +1,66 @@
+use anyhow::Result;
+use plotters::prelude::*;
+use std::path::Path;
+
+use crate::cmd::monitor::{Unit, human_rate};
+
+/// Rate samples of one interface: (seconds since start, bytes/s)
+pub struct RateSeries {
+    pub name: String,
+    pub points: Vec<(f64, f64)>,
+}
+
+/// Render per-interface rate history as a PNG line chart
+pub fn render_rate_png(path: &Path, series: &[RateSeries], unit: Unit) -> Result<()> {
+    let (scale, label) = match unit {
+        Unit::Bytes => (1.0, "Rate (B/s)"),
+        Unit::Bits => (8.0, "Rate (b/s)"),
+    };
+    // Long runs only keep the most recent points, so the axis starts at the oldest
+    let min_x = series
+        .iter()
+        .flat_map(|s| s.points.iter().map(|p| p.0))
+        .fold(f64::INFINITY, f64::min);
+    let min_x = if min_x.is_finite() { min_x } else { 0.0 };
+    let max_x = series
+        .iter()
+        .flat_map(|s| s.points.iter().map(|p| p.0))
+        .fold(min_x + 1.0, f64::max);
+    let max_y = series
+        .iter()
+        .flat_map(|s| s.points.iter().map(|p| p.1 * scale))
+        .fold(1.0, f64::max);
+
+    let root = BitMapBackend::new(path, (1024, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        .caption("nifa monitor - throughput (RX+TX)", ("sans-serif", 24))
+        .margin(16)
+        .x_label_area_size(40)
+        .y_label_area_size(110)
+        .build_cartesian_2d(min_x..max_x, 0.0..max_y * 1.05)?;
+    chart
+        .configure_mesh()
+        .x_desc("Time (s)")
+        .y_desc(label)
+        .y_label_formatter(&|v| human_rate(v / scale, unit))
+        .draw()?;
+
+    for (i, s) in series.iter().enumerate() {
+        let color = Palette99::pick(i).to_rgba();
+        chart
+            .draw_series(LineSeries::new(
+                s.points.iter().map(|&(x, y)| (x, y * scale)),
+                color.stroke_width(2),
+            ))?
+            .label(s.name.clone())
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+    }
+    chart
+        .configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+    root.present()?;
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result, anyhow};
+use minijinja::Environment;
+use serde::Serialize;
+
+/// Render a snapshot (or the `os` report) through a user-supplied minijinja template.
+/// Its fields (`sys`, `interfaces`, ...) are the template context.
+pub fn render_snapshot<T: Serialize>(path: &Path, snapshot: &T) -> Result<String> {
+    let source =
+        fs::read_to_string(path).with_context(|| format!("read template {}", path.display()))?;
+    let name = path.display().to_string();
+    let mut env = Environment::new();
+    env.set_keep_trailing_newline(true);
+    env.add_template(&name, &source)
+        .map_err(|e| template_error(&name, e))?;
+    let template = env
+        .get_template(&name)
+        .map_err(|e| template_error(&name, e))?;
+    template
+        .render(snapshot)
+        .map_err(|e| template_error(&name, e))
+}
+
+/// Format a template error as `path:line: kind: detail`
+fn template_error(name: &str, err: minijinja::Error) -> anyhow::Error {
+    let detail = match err.detail() {
+        Some(detail) => format!("{}: {}", err.kind(), detail),
+        None => err.kind().to_string(),
+    };
+    match err.line() {
+        Some(line) => anyhow!("{}:{}: {}", name, line, detail),
+        None => anyhow!("{}: {}", name, detail),
+    }
+}
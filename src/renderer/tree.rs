@@ -1,8 +1,104 @@
+use netdev::interface::{InterfaceType, OperState};
+use netdev::ipnet::Ipv4Net;
 use netdev::{Interface, MacAddr};
-use termtree::Tree;
+use std::io::IsTerminal;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::path::Path;
+use termtree::{GlyphPalette, Tree};
 use url::Url;
 
-use crate::{collector::sys::SysInfo, db::oui::is_oui_db_initialized, model::ipinfo::PublicOut};
+use crate::{
+    cli::{Cli, Section},
+    cmd::monitor::{Rate, Unit, human_rate, human_total},
+    cmd::vpn::VpnReport,
+    collector::IfaceLookups,
+    collector::dhcp::DhcpInfo,
+    collector::iface::{TrafficTotals, TypeTotals, VpnHeuristic},
+    collector::sys::{SocketCounts, SysInfo},
+    config::display_name,
+    db::oui::is_oui_db_initialized,
+    model::addr::{Ipv6Lifetime, Ipv6RaInfo},
+    model::check::{CheckReport, Level},
+    model::connectivity::Connectivity,
+    model::dns::DohHeuristic,
+    model::iface::InterfaceChange,
+    model::ipinfo::PublicOut,
+    model::neighbor::NeighborEntry,
+    model::topology::LinkTopology,
+};
+
+/// ASCII-only connectors for terminals/log viewers without box-drawing support
+const ASCII_GLYPHS: GlyphPalette = GlyphPalette {
+    middle_item: "+",
+    last_item: "`",
+    item_indent: "-- ",
+    middle_skip: "|",
+    last_skip: " ",
+    skip_indent: "   ",
+};
+
+/// Options shared by the tree renderers
+#[derive(Debug, Clone, Default)]
+pub struct TreeOptions {
+    /// Use ASCII connectors instead of Unicode box-drawing
+    pub ascii: bool,
+    /// Sections omitted from the output
+    pub hide: Vec<Section>,
+    /// Emit ANSI colors (off with --no-color / NO_COLOR or when stdout is not a terminal)
+    pub color: bool,
+    /// Prefix interface names with a type glyph
+    pub icons: bool,
+}
+
+impl TreeOptions {
+    pub fn from_cli(cli: &Cli) -> Self {
+        TreeOptions {
+            ascii: cli.ascii,
+            hide: cli.hide.clone(),
+            color: cli.use_color() && std::io::stdout().is_terminal(),
+            icons: cli.icons,
+        }
+    }
+
+    /// Whether the given section should be rendered
+    pub fn shows(&self, section: Section) -> bool {
+        !self.hide.contains(&section)
+    }
+
+    /// Interface name with its type glyph prepended when `--icons` is set
+    pub fn iface_name(&self, iface: &Interface) -> String {
+        let name = display_name(&iface.name);
+        match type_icon(iface.if_type, self.ascii) {
+            Some(icon) if self.icons => format!("{} {}", icon, name),
+            _ => name,
+        }
+    }
+
+    /// Glyph palette for the tree connectors
+    pub fn glyphs(&self) -> GlyphPalette {
+        if self.ascii {
+            ASCII_GLYPHS
+        } else {
+            GlyphPalette::new()
+        }
+    }
+}
+
+/// Glyph for an interface type (ASCII tag when `ascii`); None for types without one
+fn type_icon(if_type: InterfaceType, ascii: bool) -> Option<&'static str> {
+    let (emoji, tag) = match if_type {
+        InterfaceType::Ethernet
+        | InterfaceType::Ethernet3Megabit
+        | InterfaceType::FastEthernetT
+        | InterfaceType::FastEthernetFx
+        | InterfaceType::GigabitEthernet => ("🔌", "[eth]"),
+        InterfaceType::Wireless80211 => ("📶", "[wifi]"),
+        InterfaceType::Loopback => ("🔁", "[lo]"),
+        InterfaceType::Tunnel | InterfaceType::Ppp => ("🔒", "[tun]"),
+        _ => return None,
+    };
+    Some(if ascii { tag } else { emoji })
+}
 
 /// Convert a string into a tree label.
 pub fn tree_label<S: Into<String>>(s: S) -> String {
@@ -23,10 +119,210 @@ pub fn fmt_bps(bps: u64) -> String {
     }
 }
 
+/// "Search Domains" subtree
+fn search_domains_node(domains: &[String]) -> Tree<String> {
+    let mut node = Tree::new(tree_label("Search Domains"));
+    for d in domains {
+        node.push(Tree::new(d.clone()));
+    }
+    node
+}
+
+/// "Type: Ethernet", with the virtual subtype when known: "Type: Ethernet (virtual: veth)"
+pub fn type_label(iface: &Interface, lookups: &IfaceLookups) -> String {
+    match lookups.virtual_kind_of(iface) {
+        Some(kind) => format!("Type: {:?} (virtual: {})", iface.if_type, kind),
+        None => format!("Type: {:?}", iface.if_type),
+    }
+}
+
+/// "Link Speed" node label, marked when RX and TX speeds differ
+pub fn link_speed_label(iface: &Interface) -> &'static str {
+    if crate::collector::iface::has_asymmetric_speed(iface) {
+        "Link Speed (asymmetric)"
+    } else {
+        "Link Speed"
+    }
+}
+
+/// Classify an IPv4 address scope for display (None for ordinary addresses)
+pub fn ipv4_scope_label(ip: &Ipv4Addr) -> Option<&'static str> {
+    if ip.is_link_local() {
+        // 169.254.0.0/16: usually means DHCP failed
+        Some("link-local/APIPA")
+    } else {
+        None
+    }
+}
+
+/// IPv4 network label with scope annotation
+pub fn ipv4_label(net: &Ipv4Net) -> String {
+    match ipv4_scope_label(&net.addr()) {
+        Some(scope) => format!("{} ({})", net, scope),
+        None => net.to_string(),
+    }
+}
+
+fn fmt_lifetime(secs: Option<u64>) -> String {
+    match secs {
+        Some(s) => format!("{}s", s),
+        None => "forever".into(),
+    }
+}
+
+/// IPv6 address label with scope ID and, when known, preferred/valid lifetimes
+pub fn ipv6_label(iface: &Interface, i: usize, lifetimes: &[Ipv6Lifetime]) -> String {
+    let Some(net) = iface.ipv6.get(i) else {
+        return String::new();
+    };
+    let mut label = net.to_string();
+    if let Some(scope) = iface.ipv6_scope_ids.get(i) {
+        label.push_str(&format!(" (scope_id={})", scope));
+    }
+    if let Some(lt) = lifetimes
+        .iter()
+        .find(|lt| lt.iface == iface.name && lt.addr == net.addr())
+    {
+        label.push_str(&format!(
+            " (pref={} valid={})",
+            fmt_lifetime(lt.preferred_secs),
+            fmt_lifetime(lt.valid_secs)
+        ));
+    }
+    label
+}
+
+/// Gateway IPv6 address; link-local ones get the zone so they can be used as shown
+/// (`fe80::1%eth0`; the interface index on Windows)
+pub fn gateway_ipv6_label(iface: &Interface, ip: &Ipv6Addr) -> String {
+    if !ip.is_unicast_link_local() {
+        return ip.to_string();
+    }
+    if cfg!(windows) {
+        format!("{}%{}", ip, iface.index)
+    } else {
+        format!("{}%{}", ip, iface.name)
+    }
+}
+
+/// Gateway MAC, falling back to the NDP cache lookup when netdev reports all-zero
+pub fn gateway_mac_label(iface: &Interface, lookups: &IfaceLookups) -> String {
+    match &iface.gateway {
+        Some(gw) if gw.mac_addr != MacAddr::zero() => gw.mac_addr.to_string(),
+        Some(_) => match lookups.gateway_mac_of(iface) {
+            Some(mac) => format!("{} (from NDP)", mac),
+            None => "unknown".to_string(),
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+/// "IPv6 RA" node: M/O flags, router lifetime and the implied address mode
+fn ipv6_ra_node(ra: &Ipv6RaInfo) -> Tree<String> {
+    let yes_no = |b: bool| if b { "yes" } else { "no" };
+    let mut node = Tree::new(tree_label("IPv6 RA"));
+    node.push(Tree::new(format!("Received: {}", yes_no(ra.received))));
+    if ra.received {
+        node.push(Tree::new(format!("Managed (M): {}", yes_no(ra.managed))));
+        node.push(Tree::new(format!(
+            "Other config (O): {}",
+            yes_no(ra.other_config)
+        )));
+        if let Some(secs) = ra.router_lifetime_secs {
+            node.push(Tree::new(format!("Router lifetime: {}s", secs)));
+        }
+        node.push(Tree::new(format!("Mode: {}", ra.mode())));
+    }
+    node
+}
+
 pub fn fmt_flags(flags: u32) -> String {
     format!("0x{:08X}", flags)
 }
 
+/// Humanized uptime, e.g. "3d 4h 12m" (minutes only below an hour: "12m")
+fn fmt_uptime(secs: u64) -> String {
+    let (days, hours, mins) = (secs / 86_400, secs % 86_400 / 3_600, secs % 3_600 / 60);
+    match (days, hours) {
+        (0, 0) => format!("{}m", mins),
+        (0, _) => format!("{}h {}m", hours, mins),
+        _ => format!("{}d {}h {}m", days, hours, mins),
+    }
+}
+
+/// Link utilization in percent: the busier direction against its link speed.
+/// None when no direction has both a known speed and a rate.
+fn utilization_pct(iface: &Interface, rate: &Rate) -> Option<f64> {
+    let pct = |bytes_per_s: f64, speed: Option<u64>| {
+        speed
+            .filter(|bps| *bps > 0)
+            .map(|bps| bytes_per_s * 8.0 / bps as f64 * 100.0)
+    };
+    let rx = pct(rate.rx_per_s, iface.receive_speed);
+    let tx = pct(rate.tx_per_s, iface.transmit_speed);
+    match (rx, tx) {
+        (Some(rx), Some(tx)) => Some(rx.max(tx)),
+        (rx, tx) => rx.or(tx),
+    }
+}
+
+/// Green below 50%, yellow below 80%, red above
+fn colorize_utilization(pct: f64, color: bool) -> String {
+    let text = format!("{:.0}%", pct);
+    if !color {
+        return text;
+    }
+    let code = if pct < 50.0 {
+        32
+    } else if pct < 80.0 {
+        33
+    } else {
+        31
+    };
+    format!("\x1b[{}m{}\x1b[0m", code, text)
+}
+
+/// VPN heuristic worth showing: VPN-like and not a physical NIC, whose small
+/// MTU or 10/8 address alone would otherwise trip the score
+fn shown_vpn_heuristic(iface: &Interface) -> Option<VpnHeuristic> {
+    if iface.is_physical() {
+        return None;
+    }
+    Some(crate::collector::iface::detect_vpn_like(iface)).filter(|h| h.is_vpn_like)
+}
+
+/// "Heuristic" node with the score and the strongest signals
+fn vpn_heuristic_node(vpn: &VpnHeuristic) -> Tree<String> {
+    let mut node = Tree::new(tree_label("Heuristic"));
+    node.push(Tree::new(format!("VPN-like: true (score {})", vpn.score)));
+    let mut signals: Vec<&(String, i32)> = vpn.signals.iter().collect();
+    signals.sort_by_key(|(_, points)| std::cmp::Reverse(*points));
+    let mut top = Tree::new(tree_label("Top Signals"));
+    for (signal, points) in signals.into_iter().take(3) {
+        top.push(Tree::new(format!("{} (+{})", signal, points)));
+    }
+    node.push(top);
+    node
+}
+
+/// "Sockets" subtree; values the platform does not report show as "unknown"
+fn sockets_node(sockets: &SocketCounts) -> Tree<String> {
+    let count = |v: Option<u64>| v.map_or_else(|| "unknown".to_string(), |n| n.to_string());
+    let mut node = Tree::new(tree_label("Sockets"));
+    node.push(Tree::new(format!(
+        "TCP: {} ({} TIME_WAIT)",
+        count(sockets.tcp),
+        count(sockets.tcp_time_wait)
+    )));
+    node.push(Tree::new(format!("UDP: {}", count(sockets.udp))));
+    node.push(Tree::new(format!(
+        "Conntrack: {} / {} max",
+        count(sockets.conntrack),
+        count(sockets.conntrack_max)
+    )));
+    node
+}
+
 /// Mask username/password in proxy URL for privacy
 fn mask_proxy_url(raw: &str) -> String {
     if let Ok(mut url) = Url::parse(raw) {
@@ -40,8 +336,35 @@ fn mask_proxy_url(raw: &str) -> String {
     raw.to_string()
 }
 
+/// Summarize interface states and address counts,
+/// e.g. "12 interfaces (8 up, 4 down), 15 IPv4, 22 IPv6"
+fn interface_summary(ifaces: &[Interface]) -> String {
+    let up = ifaces
+        .iter()
+        .filter(|i| i.oper_state == OperState::Up)
+        .count();
+    let down = ifaces
+        .iter()
+        .filter(|i| i.oper_state == OperState::Down)
+        .count();
+    let other = ifaces.len() - up - down;
+    let v4: usize = ifaces.iter().map(|i| i.ipv4.len()).sum();
+    let v6: usize = ifaces.iter().map(|i| i.ipv6.len()).sum();
+    let mut states = format!("{} up, {} down", up, down);
+    if other > 0 {
+        states.push_str(&format!(", {} other", other));
+    }
+    format!(
+        "{} interfaces ({}), {} IPv4, {} IPv6",
+        ifaces.len(),
+        states,
+        v4,
+        v6
+    )
+}
+
 /// Print the network interfaces in a tree structure.
-pub fn print_interface_tree(ifaces: &[Interface]) {
+pub fn print_interface_tree(ifaces: &[Interface], lookups: &IfaceLookups, opts: &TreeOptions) {
     let default: bool = if ifaces.len() == 1 {
         ifaces[0].default
     } else {
@@ -53,114 +376,202 @@ pub fn print_interface_tree(ifaces: &[Interface]) {
     } else {
         Tree::new(tree_label(format!("Interfaces on {}", host)))
     };
+    if !default {
+        root.push(Tree::new(tree_label(format!(
+            "Summary: {}",
+            interface_summary(ifaces)
+        ))));
+    }
     for iface in ifaces {
-        let mut node = Tree::new(format!(
-            "{}{}",
-            iface.name,
-            if iface.default { " (default)" } else { "" }
-        ));
-
-        node.push(Tree::new(format!("Index: {}", iface.index)));
+        root.push(interface_node(iface, lookups, opts));
+    }
+    root.set_glyphs(opts.glyphs());
+    println!("{}", root);
+}
 
-        if let Some(fn_name) = &iface.friendly_name {
-            node.push(Tree::new(format!("Friendly Name: {}", fn_name)));
+/// Print the network interfaces nested by bridge/bond membership.
+pub fn print_interface_topology_tree(
+    ifaces: &[Interface],
+    links: &[LinkTopology],
+    lookups: &IfaceLookups,
+    opts: &TreeOptions,
+) {
+    let host = crate::collector::sys::hostname();
+    let mut root = Tree::new(tree_label(format!("Interface Topology on {}", host)));
+    let link_of = |name: &str| links.iter().find(|l| l.name == name);
+    for iface in ifaces {
+        let link = link_of(&iface.name);
+        // Members are rendered under their master when it is part of the set
+        if let Some(master) = link.and_then(|l| l.master.as_deref())
+            && ifaces.iter().any(|i| i.name == master)
+        {
+            continue;
         }
-        if let Some(desc) = &iface.description {
-            node.push(Tree::new(format!("Description: {}", desc)));
+        let mut node = interface_node(iface, lookups, opts);
+        if let Some(link) = link {
+            if let Some(kind) = &link.kind {
+                node.push(Tree::new(format!("Kind: {}", kind)));
+            }
+            if let Some(master) = &link.master {
+                node.push(Tree::new(format!("Master: {}", master)));
+            }
+            if !link.members.is_empty() {
+                let mut members = Tree::new(tree_label("Members"));
+                for member in &link.members {
+                    match ifaces.iter().find(|i| &i.name == member) {
+                        Some(m) => members.push(interface_node(m, lookups, opts)),
+                        None => members.push(Tree::new(display_name(member))),
+                    };
+                }
+                node.push(members);
+            }
         }
+        root.push(node);
+    }
+    root.set_glyphs(opts.glyphs());
+    println!("{}", root);
+}
+
+/// Print interfaces that changed relative to a snapshot, each marked with its change
+pub fn print_interface_change_tree(
+    changes: &[InterfaceChange],
+    snapshot: &Path,
+    lookups: &IfaceLookups,
+    opts: &TreeOptions,
+) {
+    let host = crate::collector::sys::hostname();
+    let mut root = Tree::new(tree_label(format!(
+        "Interface changes on {} since {}",
+        host,
+        snapshot.display()
+    )));
+    if changes.is_empty() {
+        root.push(Tree::new(tree_label("(no changes)")));
+    }
+    for c in changes {
+        let mut node = interface_node(&c.iface, lookups, opts);
+        node.root = format!("{} [{}]", node.root, c.change.label());
+        root.push(node);
+    }
+    root.set_glyphs(opts.glyphs());
+    println!("{}", root);
+}
 
-        node.push(Tree::new(format!("Type: {:?}", iface.if_type)));
-        node.push(Tree::new(format!("State: {:?}", iface.oper_state)));
-        if let Some(mac) = &iface.mac_addr {
-            node.push(Tree::new(format!("MAC: {}", mac)));
+/// Build the list-view node of a single interface.
+fn interface_node(iface: &Interface, lookups: &IfaceLookups, opts: &TreeOptions) -> Tree<String> {
+    let iface = &crate::collector::iface::with_sorted_addresses(iface);
+    let mut node = Tree::new(format!(
+        "{}{}",
+        opts.iface_name(iface),
+        if iface.default { " (default)" } else { "" }
+    ));
 
-            if is_oui_db_initialized() && *mac != MacAddr::zero() {
-                let oui_db = crate::db::oui::oui_db();
-                if let Some(vendor) = oui_db.lookup_mac(mac) {
-                    let vendor_name = vendor.vendor_detail.as_deref().unwrap_or(&vendor.vendor);
-                    node.push(Tree::new(format!("Vendor: {}", vendor_name)));
-                }
+    node.push(Tree::new(format!("Index: {}", iface.index)));
+
+    if let Some(fn_name) = &iface.friendly_name {
+        node.push(Tree::new(format!("Friendly Name: {}", fn_name)));
+    }
+    if let Some(desc) = &iface.description {
+        node.push(Tree::new(format!("Description: {}", desc)));
+    }
+
+    node.push(Tree::new(type_label(iface, lookups)));
+    node.push(Tree::new(format!("State: {:?}", iface.oper_state)));
+    if opts.shows(Section::Mac)
+        && let Some(mac) = &iface.mac_addr
+    {
+        node.push(Tree::new(format!("MAC: {}", mac)));
+
+        if is_oui_db_initialized() && *mac != MacAddr::zero() {
+            let oui_db = crate::db::oui::oui_db();
+            if let Some(vendor) = oui_db.lookup_mac(mac) {
+                let vendor_name = vendor.vendor_detail.as_deref().unwrap_or(&vendor.vendor);
+                node.push(Tree::new(format!("Vendor: {}", vendor_name)));
             }
         }
+    }
 
-        if let Some(mtu) = iface.mtu {
-            node.push(Tree::new(format!("MTU: {}", mtu)));
-        }
+    if opts.shows(Section::Mtu)
+        && let Some(mtu) = iface.mtu
+    {
+        node.push(Tree::new(format!("MTU: {}", mtu)));
+    }
 
-        if !iface.ipv4.is_empty() {
-            let mut ipv4_tree = Tree::new(tree_label("IPv4"));
-            for net in &iface.ipv4 {
-                ipv4_tree.push(Tree::new(net.to_string()));
-            }
-            node.push(ipv4_tree);
+    if opts.shows(Section::Ipv4) && !iface.ipv4.is_empty() {
+        let mut ipv4_tree = Tree::new(tree_label("IPv4"));
+        for net in &iface.ipv4 {
+            ipv4_tree.push(Tree::new(ipv4_label(net)));
         }
+        node.push(ipv4_tree);
+    }
 
-        if !iface.ipv6.is_empty() {
-            let mut ipv6_tree = Tree::new(tree_label("IPv6"));
-            for (i, net) in iface.ipv6.iter().enumerate() {
-                let mut label = net.to_string();
-                if let Some(scope) = iface.ipv6_scope_ids.get(i) {
-                    label.push_str(&format!(" (scope_id={})", scope));
-                }
-                ipv6_tree.push(Tree::new(label));
-            }
-            node.push(ipv6_tree);
+    if opts.shows(Section::Ipv6) && !iface.ipv6.is_empty() {
+        let mut ipv6_tree = Tree::new(tree_label("IPv6"));
+        for i in 0..iface.ipv6.len() {
+            ipv6_tree.push(Tree::new(ipv6_label(iface, i, &lookups.ipv6_lifetimes)));
         }
+        node.push(ipv6_tree);
+    }
 
-        if !iface.dns_servers.is_empty() {
-            let mut dns_tree = Tree::new(tree_label("DNS"));
-            for dns in &iface.dns_servers {
-                dns_tree.push(Tree::new(dns.to_string()));
-            }
-            node.push(dns_tree);
+    if opts.shows(Section::Dns) && !iface.dns_servers.is_empty() {
+        let mut dns_tree = Tree::new(tree_label("DNS"));
+        for dns in &iface.dns_servers {
+            dns_tree.push(Tree::new(dns.to_string()));
         }
+        node.push(dns_tree);
+    }
 
-        if let Some(gw) = &iface.gateway {
-            let mut gw_node = Tree::new(tree_label("Gateway"));
-            // GW MAC
-            gw_node.push(Tree::new(format!("MAC: {}", gw.mac_addr)));
-            // GW IPv4/IPv6
-            if !gw.ipv4.is_empty() {
-                let mut gw_tree = Tree::new(tree_label("IPv4"));
-                for ip in &gw.ipv4 {
-                    gw_tree.push(Tree::new(ip.to_string()));
-                }
-                gw_node.push(gw_tree);
-            }
-            if !gw.ipv6.is_empty() {
-                let mut gw_tree = Tree::new(tree_label("IPv6"));
-                for ip in &gw.ipv6 {
-                    gw_tree.push(Tree::new(ip.to_string()));
-                }
-                gw_node.push(gw_tree);
+    if opts.shows(Section::Gateway)
+        && let Some(gw) = &iface.gateway
+    {
+        let mut gw_node = Tree::new(tree_label("Gateway"));
+        // GW MAC
+        gw_node.push(Tree::new(format!(
+            "MAC: {}",
+            gateway_mac_label(iface, lookups)
+        )));
+        // GW IPv4/IPv6
+        if !gw.ipv4.is_empty() {
+            let mut gw_tree = Tree::new(tree_label("IPv4"));
+            for ip in &gw.ipv4 {
+                gw_tree.push(Tree::new(ip.to_string()));
             }
-            node.push(gw_node);
+            gw_node.push(gw_tree);
         }
-
-        if iface.default {
-            let vpn_heuristic = crate::collector::iface::detect_vpn_like(&iface);
-            if vpn_heuristic.is_vpn_like {
-                let mut heuristic_node = Tree::new(tree_label("Heuristic"));
-                heuristic_node.push(Tree::new(format!(
-                    "VPN-like: {}",
-                    vpn_heuristic.is_vpn_like
-                )));
-                node.push(heuristic_node);
+        if !gw.ipv6.is_empty() {
+            let mut gw_tree = Tree::new(tree_label("IPv6"));
+            for ip in &gw.ipv6 {
+                gw_tree.push(Tree::new(gateway_ipv6_label(iface, ip)));
             }
+            gw_node.push(gw_tree);
         }
+        node.push(gw_node);
+    }
 
-        root.push(node);
+    if iface.default
+        && let Some(vpn) = shown_vpn_heuristic(iface)
+    {
+        node.push(vpn_heuristic_node(&vpn));
     }
-    println!("{}", root);
+
+    node
 }
 
 /// Print detailed information of a single interface in a tree structure.
-pub fn print_interface_detail_tree(iface: &Interface) {
+pub fn print_interface_detail_tree(
+    iface: &Interface,
+    rate: Option<&Rate>,
+    lookups: &IfaceLookups,
+    opts: &TreeOptions,
+) {
+    let iface = &crate::collector::iface::with_sorted_addresses(iface);
     let host = crate::collector::sys::hostname();
+    let vpn = shown_vpn_heuristic(iface);
     let title = format!(
-        "{}{} on {}",
-        iface.name,
+        "{}{}{} on {}",
+        opts.iface_name(iface),
         if iface.default { " (default)" } else { "" },
+        if vpn.is_some() { " (VPN-like)" } else { "" },
         host
     );
     let mut root = Tree::new(tree_label(title));
@@ -175,10 +586,12 @@ pub fn print_interface_detail_tree(iface: &Interface) {
         root.push(Tree::new(format!("Description: {}", desc)));
     }
 
-    root.push(Tree::new(format!("Type: {:?}", iface.if_type)));
+    root.push(Tree::new(type_label(iface, lookups)));
     root.push(Tree::new(format!("State: {:?}", iface.oper_state)));
 
-    if let Some(mac) = &iface.mac_addr {
+    if opts.shows(Section::Mac)
+        && let Some(mac) = &iface.mac_addr
+    {
         root.push(Tree::new(format!("MAC: {}", mac)));
 
         if is_oui_db_initialized() && *mac != MacAddr::zero() {
@@ -190,13 +603,17 @@ pub fn print_interface_detail_tree(iface: &Interface) {
         }
     }
 
-    if let Some(mtu) = iface.mtu {
+    if opts.shows(Section::Mtu)
+        && let Some(mtu) = iface.mtu
+    {
         root.push(Tree::new(format!("MTU: {}", mtu)));
     }
 
     // link speeds (humanized bps)
-    if iface.transmit_speed.is_some() || iface.receive_speed.is_some() {
-        let mut speed = Tree::new(tree_label("Link Speed"));
+    if opts.shows(Section::Speed)
+        && (iface.transmit_speed.is_some() || iface.receive_speed.is_some())
+    {
+        let mut speed = Tree::new(tree_label(link_speed_label(iface)));
         if let Some(tx) = iface.transmit_speed {
             speed.push(Tree::new(format!("TX: {}", fmt_bps(tx))));
         }
@@ -206,43 +623,93 @@ pub fn print_interface_detail_tree(iface: &Interface) {
         root.push(speed);
     }
 
+    // duplex / autonegotiation (omitted when unknown)
+    if opts.shows(Section::Speed)
+        && let Some(mode) = lookups.link_mode_of(iface)
+    {
+        let mut link = Tree::new(tree_label("Link"));
+        if let Some(duplex) = &mode.duplex {
+            link.push(Tree::new(format!("Duplex: {}", duplex)));
+        }
+        if let Some(autoneg) = mode.autoneg {
+            link.push(Tree::new(format!(
+                "Autoneg: {}",
+                if autoneg { "on" } else { "off" }
+            )));
+        }
+        root.push(link);
+    }
+
     // flags
-    root.push(Tree::new(format!("Flags: {}", fmt_flags(iface.flags))));
+    if opts.shows(Section::Flags) {
+        root.push(Tree::new(format!("Flags: {}", fmt_flags(iface.flags))));
+        if let Some(promisc) = crate::collector::iface::is_promiscuous(iface) {
+            root.push(Tree::new(format!(
+                "Promiscuous: {}",
+                if promisc { "yes" } else { "no" }
+            )));
+        }
+    }
+    if let Some(zone) = lookups.zone_of(iface) {
+        root.push(Tree::new(zone.label()));
+    }
 
     // ---- Addresses ----
-    if !iface.ipv4.is_empty() {
+    if opts.shows(Section::Ipv4) && !iface.ipv4.is_empty() {
         let mut ipv4_tree = Tree::new(tree_label("IPv4"));
         for net in &iface.ipv4 {
-            ipv4_tree.push(Tree::new(net.to_string()));
+            ipv4_tree.push(Tree::new(ipv4_label(net)));
         }
         root.push(ipv4_tree);
     }
 
-    if !iface.ipv6.is_empty() {
+    if opts.shows(Section::Ipv6) && !iface.ipv6.is_empty() {
         let mut ipv6_tree = Tree::new(tree_label("IPv6"));
-        for (i, net) in iface.ipv6.iter().enumerate() {
-            let mut label = net.to_string();
-            if let Some(scope) = iface.ipv6_scope_ids.get(i) {
-                label.push_str(&format!(" (scope_id={})", scope));
-            }
-            ipv6_tree.push(Tree::new(label));
+        for i in 0..iface.ipv6.len() {
+            ipv6_tree.push(Tree::new(ipv6_label(iface, i, &lookups.ipv6_lifetimes)));
         }
         root.push(ipv6_tree);
     }
+    if opts.shows(Section::Ipv6)
+        && let Some(ra) = lookups.ra_of(iface)
+    {
+        root.push(ipv6_ra_node(ra));
+    }
+
+    // ---- Multicast ----
+    let groups: Vec<_> = lookups.multicast_groups_of(iface).collect();
+    if !groups.is_empty() {
+        let mut mc_tree = Tree::new(tree_label("Multicast Groups"));
+        for g in groups {
+            mc_tree.push(Tree::new(format!("{} (users: {})", g.group, g.users)));
+        }
+        root.push(mc_tree);
+    }
 
     // ---- DNS ----
-    if !iface.dns_servers.is_empty() {
+    if opts.shows(Section::Dns) && !iface.dns_servers.is_empty() {
         let mut dns_tree = Tree::new(tree_label("DNS"));
         for dns in &iface.dns_servers {
             dns_tree.push(Tree::new(dns.to_string()));
         }
         root.push(dns_tree);
     }
+    if opts.shows(Section::Dns) {
+        let domains = lookups.search_domains_of(iface);
+        if !domains.is_empty() {
+            root.push(search_domains_node(domains));
+        }
+    }
 
     // ---- Gateway ----
-    if let Some(gw) = &iface.gateway {
+    if opts.shows(Section::Gateway)
+        && let Some(gw) = &iface.gateway
+    {
         let mut gw_node = Tree::new(tree_label("Gateway"));
-        gw_node.push(Tree::new(format!("MAC: {}", gw.mac_addr)));
+        gw_node.push(Tree::new(format!(
+            "MAC: {}",
+            gateway_mac_label(iface, lookups)
+        )));
         if !gw.ipv4.is_empty() {
             let mut gw4 = Tree::new(tree_label("IPv4"));
             for ip in &gw.ipv4 {
@@ -253,7 +720,7 @@ pub fn print_interface_detail_tree(iface: &Interface) {
         if !gw.ipv6.is_empty() {
             let mut gw6 = Tree::new(tree_label("IPv6"));
             for ip in &gw.ipv6 {
-                gw6.push(Tree::new(ip.to_string()));
+                gw6.push(Tree::new(gateway_ipv6_label(iface, ip)));
             }
             gw_node.push(gw6);
         }
@@ -261,27 +728,50 @@ pub fn print_interface_detail_tree(iface: &Interface) {
     }
 
     // ---- Statistics (snapshot) ----
-    if let Some(st) = &iface.stats {
+    if opts.shows(Section::Stats)
+        && let Some(st) = &iface.stats
+    {
         let mut stats_node = Tree::new(tree_label("Statistics (snapshot)"));
         stats_node.push(Tree::new(format!("RX bytes: {}", st.rx_bytes)));
         stats_node.push(Tree::new(format!("TX bytes: {}", st.tx_bytes)));
+        if let Some(rate) = rate {
+            stats_node.push(Tree::new(format!(
+                "RX/s: {}",
+                human_rate(rate.rx_per_s, Unit::Bytes)
+            )));
+            stats_node.push(Tree::new(format!(
+                "TX/s: {}",
+                human_rate(rate.tx_per_s, Unit::Bytes)
+            )));
+            if let Some(pct) = utilization_pct(iface, rate) {
+                stats_node.push(Tree::new(format!(
+                    "Utilization: {}",
+                    colorize_utilization(pct, opts.color)
+                )));
+            }
+        }
         root.push(stats_node);
     }
 
-    let vpn_heuristic = crate::collector::iface::detect_vpn_like(&iface);
-    if vpn_heuristic.is_vpn_like {
-        let mut heuristic_node = Tree::new(tree_label("Heuristic"));
-        heuristic_node.push(Tree::new(format!(
-            "VPN-like: {}",
-            vpn_heuristic.is_vpn_like
-        )));
-        root.push(heuristic_node);
+    if let Some(vpn) = &vpn {
+        root.push(vpn_heuristic_node(vpn));
     }
 
+    root.set_glyphs(opts.glyphs());
     println!("{}", root);
 }
 
-pub fn print_system_with_default_iface(sys: &SysInfo, default_iface: Option<Interface>) {
+#[allow(clippy::too_many_arguments)]
+pub fn print_system_with_default_iface(
+    sys: &SysInfo,
+    default_iface: Option<Interface>,
+    lookups: &IfaceLookups,
+    dhcp: Option<&DhcpInfo>,
+    totals: &TrafficTotals,
+    unit: Unit,
+    doh: &DohHeuristic,
+    opts: &TreeOptions,
+) {
     let mut root = Tree::new(tree_label(format!(
         "System Information on {}",
         sys.hostname
@@ -304,6 +794,28 @@ pub fn print_system_with_default_iface(sys: &SysInfo, default_iface: Option<Inte
         "Architecture: {}",
         sys.architecture
     ))));
+    if let Some(secs) = sys.uptime_secs {
+        sys_node.push(Tree::new(tree_label(format!(
+            "Uptime: {}",
+            fmt_uptime(secs)
+        ))));
+    }
+    if let Some(boot) = &sys.boot_time {
+        sys_node.push(Tree::new(tree_label(format!("Boot Time: {}", boot))));
+    }
+    let on_off = |v: Option<bool>| match v {
+        Some(true) => "on",
+        Some(false) => "off",
+        None => "unknown",
+    };
+    sys_node.push(Tree::new(tree_label(format!(
+        "IPv4 Forwarding: {}",
+        on_off(sys.ipv4_forwarding)
+    ))));
+    sys_node.push(Tree::new(tree_label(format!(
+        "IPv6 Forwarding: {}",
+        on_off(sys.ipv6_forwarding)
+    ))));
 
     // ---- Proxy (env) ----
     let px = crate::collector::sys::collect_proxy_env();
@@ -357,9 +869,77 @@ pub fn print_system_with_default_iface(sys: &SysInfo, default_iface: Option<Inte
 
     root.push(sys_node);
 
+    // ---- Sockets (os --sockets) ----
+    if let Some(sockets) = &sys.sockets {
+        root.push(sockets_node(sockets));
+    }
+
+    // ---- Total Traffic (loopback excluded unless --include-loopback) ----
+    let mut total_node = Tree::new(tree_label(format!(
+        "Total Traffic ({} interfaces, {} loopback)",
+        totals.interfaces,
+        if totals.include_loopback {
+            "including"
+        } else {
+            "excluding"
+        }
+    )));
+    total_node.push(Tree::new(tree_label(format!(
+        "RX: {}",
+        human_total(totals.rx_bytes, unit)
+    ))));
+    total_node.push(Tree::new(tree_label(format!(
+        "TX: {}",
+        human_total(totals.tx_bytes, unit)
+    ))));
+    root.push(total_node);
+
+    // ---- DNS Privacy (heuristic) ----
+    let yes_no = |b: bool| if b { "yes" } else { "no" };
+    let mut doh_node = Tree::new(tree_label("DNS Privacy"));
+    if !doh.resolvers.is_empty() {
+        let list: Vec<String> = doh.resolvers.iter().map(|ip| ip.to_string()).collect();
+        doh_node.push(Tree::new(tree_label(format!(
+            "Resolvers: {}",
+            list.join(", ")
+        ))));
+    }
+    doh_node.push(Tree::new(tree_label(format!(
+        "Loopback stub: {}",
+        yes_no(doh.stub_resolver)
+    ))));
+    doh_node.push(Tree::new(tree_label(format!(
+        "DoT configured: {}",
+        yes_no(doh.dot_configured)
+    ))));
+    doh_node.push(Tree::new(tree_label(format!(
+        "DoH likely: {}",
+        yes_no(doh.doh_likely)
+    ))));
+    if !doh.probes.is_empty() {
+        let mut probes = Tree::new(tree_label("DoH Endpoints"));
+        for p in &doh.probes {
+            let status = match (p.reachable, p.latency_ms, &p.error) {
+                (true, Some(ms), _) => format!("reachable ({} ms)", ms),
+                (true, None, _) => "reachable".to_string(),
+                (false, _, Some(e)) => format!("unreachable ({})", e),
+                (false, _, None) => "unreachable".to_string(),
+            };
+            probes.push(Tree::new(format!("{}: {}", p.url, status)));
+        }
+        doh_node.push(probes);
+    }
+    root.push(doh_node);
+
     // ---- Default Interface (optional) ----
-    if let Some(iface) = default_iface {
-        let mut if_node = Tree::new(tree_label(format!("Default Interface: {}", iface.name)));
+    if let Some(mut iface) = default_iface {
+        crate::collector::iface::sort_addresses(&mut iface);
+        let vpn = shown_vpn_heuristic(&iface);
+        let mut if_node = Tree::new(tree_label(format!(
+            "Default Interface: {}{}",
+            opts.iface_name(&iface),
+            if vpn.is_some() { " (VPN-like)" } else { "" }
+        )));
 
         if let Some(fn_name) = &iface.friendly_name {
             if_node.push(Tree::new(tree_label(format!("Friendly Name: {}", fn_name))));
@@ -369,12 +949,14 @@ pub fn print_system_with_default_iface(sys: &SysInfo, default_iface: Option<Inte
         }
 
         if_node.push(Tree::new(tree_label(format!("Index: {}", iface.index))));
-        if_node.push(Tree::new(tree_label(format!("Type: {:?}", iface.if_type))));
+        if_node.push(Tree::new(tree_label(type_label(&iface, lookups))));
         if_node.push(Tree::new(tree_label(format!(
             "State: {:?}",
             iface.oper_state
         ))));
-        if let Some(mac) = &iface.mac_addr {
+        if opts.shows(Section::Mac)
+            && let Some(mac) = &iface.mac_addr
+        {
             if_node.push(Tree::new(tree_label(format!("MAC: {}", mac))));
 
             if is_oui_db_initialized() && *mac != MacAddr::zero() {
@@ -386,13 +968,17 @@ pub fn print_system_with_default_iface(sys: &SysInfo, default_iface: Option<Inte
             }
         }
 
-        if let Some(mtu) = iface.mtu {
+        if opts.shows(Section::Mtu)
+            && let Some(mtu) = iface.mtu
+        {
             if_node.push(Tree::new(tree_label(format!("MTU: {}", mtu))));
         }
 
         // Speeds
-        if iface.transmit_speed.is_some() || iface.receive_speed.is_some() {
-            let mut speed = Tree::new(tree_label("Link Speed"));
+        if opts.shows(Section::Speed)
+            && (iface.transmit_speed.is_some() || iface.receive_speed.is_some())
+        {
+            let mut speed = Tree::new(tree_label(link_speed_label(&iface)));
             if let Some(tx) = iface.transmit_speed {
                 speed.push(Tree::new(tree_label(format!("TX: {}", fmt_bps(tx)))));
             }
@@ -403,39 +989,50 @@ pub fn print_system_with_default_iface(sys: &SysInfo, default_iface: Option<Inte
         }
 
         // IPv4
-        if !iface.ipv4.is_empty() {
+        if opts.shows(Section::Ipv4) && !iface.ipv4.is_empty() {
             let mut ipv4_node = Tree::new(tree_label("IPv4"));
             for n in &iface.ipv4 {
-                ipv4_node.push(Tree::new(tree_label(n.to_string())));
+                ipv4_node.push(Tree::new(tree_label(ipv4_label(n))));
             }
             if_node.push(ipv4_node);
         }
         // IPv6 with scope ID
-        if !iface.ipv6.is_empty() {
+        if opts.shows(Section::Ipv6) && !iface.ipv6.is_empty() {
             let mut ipv6_node = Tree::new(tree_label("IPv6"));
-            for (i, n) in iface.ipv6.iter().enumerate() {
-                let mut label = n.to_string();
-                if let Some(sc) = iface.ipv6_scope_ids.get(i) {
-                    label.push_str(&format!(" (scope_id={})", sc));
-                }
-                ipv6_node.push(Tree::new(tree_label(label)));
+            for i in 0..iface.ipv6.len() {
+                ipv6_node.push(Tree::new(tree_label(ipv6_label(
+                    &iface,
+                    i,
+                    &lookups.ipv6_lifetimes,
+                ))));
             }
             if_node.push(ipv6_node);
         }
 
         // DNS
-        if !iface.dns_servers.is_empty() {
+        if opts.shows(Section::Dns) && !iface.dns_servers.is_empty() {
             let mut dns = Tree::new(tree_label("DNS"));
             for s in &iface.dns_servers {
                 dns.push(Tree::new(tree_label(s.to_string())));
             }
             if_node.push(dns);
         }
+        if opts.shows(Section::Dns) {
+            let domains = lookups.search_domains_of(&iface);
+            if !domains.is_empty() {
+                if_node.push(search_domains_node(domains));
+            }
+        }
 
         // Gateway (IP + MAC)
-        if let Some(gw) = &iface.gateway {
+        if opts.shows(Section::Gateway)
+            && let Some(gw) = &iface.gateway
+        {
             let mut gw_node = Tree::new(tree_label("Gateway"));
-            gw_node.push(Tree::new(tree_label(format!("MAC: {}", gw.mac_addr))));
+            gw_node.push(Tree::new(tree_label(format!(
+                "MAC: {}",
+                gateway_mac_label(&iface, lookups)
+            ))));
             if !gw.ipv4.is_empty() {
                 let mut gw4 = Tree::new(tree_label("IPv4"));
                 for ip in &gw.ipv4 {
@@ -446,21 +1043,43 @@ pub fn print_system_with_default_iface(sys: &SysInfo, default_iface: Option<Inte
             if !gw.ipv6.is_empty() {
                 let mut gw6 = Tree::new(tree_label("IPv6"));
                 for ip in &gw.ipv6 {
-                    gw6.push(Tree::new(tree_label(ip.to_string())));
+                    gw6.push(Tree::new(tree_label(gateway_ipv6_label(&iface, ip))));
                 }
                 gw_node.push(gw6);
             }
             if_node.push(gw_node);
         }
 
-        let vpn_heuristic = crate::collector::iface::detect_vpn_like(&iface);
-        if vpn_heuristic.is_vpn_like {
-            let mut heuristic_node = Tree::new(tree_label("Heuristic"));
-            heuristic_node.push(Tree::new(format!(
-                "VPN-like: {}",
-                vpn_heuristic.is_vpn_like
-            )));
-            if_node.push(heuristic_node);
+        // DHCP lease
+        if opts.shows(Section::Dhcp)
+            && let Some(d) = dhcp
+        {
+            let mut dhcp_node = Tree::new(tree_label("DHCP"));
+            dhcp_node.push(Tree::new(tree_label(format!("Source: {}", d.source))));
+            if let Some(server) = &d.server {
+                dhcp_node.push(Tree::new(tree_label(format!("Server: {}", server))));
+            }
+            if let Some(t) = &d.file_modified {
+                dhcp_node.push(Tree::new(tree_label(format!("Lease file modified: {}", t))));
+            }
+            if let Some(t) = &d.expires {
+                dhcp_node.push(Tree::new(tree_label(format!("Expires: {}", t))));
+            }
+            if_node.push(dhcp_node);
+        }
+
+        if opts.shows(Section::Ipv6)
+            && let Some(ra) = lookups.ra_of(&iface)
+        {
+            if_node.push(ipv6_ra_node(ra));
+        }
+
+        if let Some(zone) = lookups.zone_of(&iface) {
+            if_node.push(Tree::new(zone.label()));
+        }
+
+        if let Some(vpn) = &vpn {
+            if_node.push(vpn_heuristic_node(vpn));
         }
 
         root.push(if_node);
@@ -468,10 +1087,16 @@ pub fn print_system_with_default_iface(sys: &SysInfo, default_iface: Option<Inte
         root.push(Tree::new(tree_label("Default Interface: (not found)")));
     }
 
+    root.set_glyphs(opts.glyphs());
     println!("{}", root);
 }
 
-pub fn print_public_ip_tree(out: &PublicOut, default_iface: Option<Interface>) {
+pub fn print_public_ip_tree(
+    out: &PublicOut,
+    default_iface: Option<Interface>,
+    lookups: &IfaceLookups,
+    opts: &TreeOptions,
+) {
     let host = crate::collector::sys::hostname();
     let mut root = Tree::new(tree_label(format!("Public IPs on {}", host)));
 
@@ -534,8 +1159,14 @@ pub fn print_public_ip_tree(out: &PublicOut, default_iface: Option<Interface>) {
     }
 
     // ---- Default Interface (optional) ----
-    if let Some(iface) = default_iface {
-        let mut if_node = Tree::new(tree_label(format!("Default Interface: {}", iface.name)));
+    if let Some(mut iface) = default_iface {
+        crate::collector::iface::sort_addresses(&mut iface);
+        let vpn = shown_vpn_heuristic(&iface);
+        let mut if_node = Tree::new(tree_label(format!(
+            "Default Interface: {}{}",
+            opts.iface_name(&iface),
+            if vpn.is_some() { " (VPN-like)" } else { "" }
+        )));
 
         if let Some(fn_name) = &iface.friendly_name {
             if_node.push(Tree::new(tree_label(format!("Friendly Name: {}", fn_name))));
@@ -545,12 +1176,14 @@ pub fn print_public_ip_tree(out: &PublicOut, default_iface: Option<Interface>) {
         }
 
         if_node.push(Tree::new(tree_label(format!("Index: {}", iface.index))));
-        if_node.push(Tree::new(tree_label(format!("Type: {:?}", iface.if_type))));
+        if_node.push(Tree::new(tree_label(type_label(&iface, lookups))));
         if_node.push(Tree::new(tree_label(format!(
             "State: {:?}",
             iface.oper_state
         ))));
-        if let Some(mac) = &iface.mac_addr {
+        if opts.shows(Section::Mac)
+            && let Some(mac) = &iface.mac_addr
+        {
             if_node.push(Tree::new(tree_label(format!("MAC: {}", mac))));
 
             if is_oui_db_initialized() && *mac != MacAddr::zero() {
@@ -562,13 +1195,17 @@ pub fn print_public_ip_tree(out: &PublicOut, default_iface: Option<Interface>) {
             }
         }
 
-        if let Some(mtu) = iface.mtu {
+        if opts.shows(Section::Mtu)
+            && let Some(mtu) = iface.mtu
+        {
             if_node.push(Tree::new(tree_label(format!("MTU: {}", mtu))));
         }
 
         // Speeds
-        if iface.transmit_speed.is_some() || iface.receive_speed.is_some() {
-            let mut speed = Tree::new(tree_label("Link Speed"));
+        if opts.shows(Section::Speed)
+            && (iface.transmit_speed.is_some() || iface.receive_speed.is_some())
+        {
+            let mut speed = Tree::new(tree_label(link_speed_label(&iface)));
             if let Some(tx) = iface.transmit_speed {
                 speed.push(Tree::new(tree_label(format!("TX: {}", fmt_bps(tx)))));
             }
@@ -579,39 +1216,50 @@ pub fn print_public_ip_tree(out: &PublicOut, default_iface: Option<Interface>) {
         }
 
         // IPv4
-        if !iface.ipv4.is_empty() {
+        if opts.shows(Section::Ipv4) && !iface.ipv4.is_empty() {
             let mut ipv4_node = Tree::new(tree_label("IPv4"));
             for n in &iface.ipv4 {
-                ipv4_node.push(Tree::new(tree_label(n.to_string())));
+                ipv4_node.push(Tree::new(tree_label(ipv4_label(n))));
             }
             if_node.push(ipv4_node);
         }
         // IPv6 with scope ID
-        if !iface.ipv6.is_empty() {
+        if opts.shows(Section::Ipv6) && !iface.ipv6.is_empty() {
             let mut ipv6_node = Tree::new(tree_label("IPv6"));
-            for (i, n) in iface.ipv6.iter().enumerate() {
-                let mut label = n.to_string();
-                if let Some(sc) = iface.ipv6_scope_ids.get(i) {
-                    label.push_str(&format!(" (scope_id={})", sc));
-                }
-                ipv6_node.push(Tree::new(tree_label(label)));
+            for i in 0..iface.ipv6.len() {
+                ipv6_node.push(Tree::new(tree_label(ipv6_label(
+                    &iface,
+                    i,
+                    &lookups.ipv6_lifetimes,
+                ))));
             }
             if_node.push(ipv6_node);
         }
 
         // DNS
-        if !iface.dns_servers.is_empty() {
+        if opts.shows(Section::Dns) && !iface.dns_servers.is_empty() {
             let mut dns = Tree::new(tree_label("DNS"));
             for s in &iface.dns_servers {
                 dns.push(Tree::new(tree_label(s.to_string())));
             }
             if_node.push(dns);
         }
+        if opts.shows(Section::Dns) {
+            let domains = lookups.search_domains_of(&iface);
+            if !domains.is_empty() {
+                if_node.push(search_domains_node(domains));
+            }
+        }
 
         // Gateway (IP + MAC)
-        if let Some(gw) = &iface.gateway {
+        if opts.shows(Section::Gateway)
+            && let Some(gw) = &iface.gateway
+        {
             let mut gw_node = Tree::new(tree_label("Gateway"));
-            gw_node.push(Tree::new(tree_label(format!("MAC: {}", gw.mac_addr))));
+            gw_node.push(Tree::new(tree_label(format!(
+                "MAC: {}",
+                gateway_mac_label(&iface, lookups)
+            ))));
             if !gw.ipv4.is_empty() {
                 let mut gw4 = Tree::new(tree_label("IPv4"));
                 for ip in &gw.ipv4 {
@@ -622,21 +1270,15 @@ pub fn print_public_ip_tree(out: &PublicOut, default_iface: Option<Interface>) {
             if !gw.ipv6.is_empty() {
                 let mut gw6 = Tree::new(tree_label("IPv6"));
                 for ip in &gw.ipv6 {
-                    gw6.push(Tree::new(tree_label(ip.to_string())));
+                    gw6.push(Tree::new(tree_label(gateway_ipv6_label(&iface, ip))));
                 }
                 gw_node.push(gw6);
             }
             if_node.push(gw_node);
         }
 
-        let vpn_heuristic = crate::collector::iface::detect_vpn_like(&iface);
-        if vpn_heuristic.is_vpn_like {
-            let mut heuristic_node = Tree::new(tree_label("Heuristic"));
-            heuristic_node.push(Tree::new(format!(
-                "VPN-like: {}",
-                vpn_heuristic.is_vpn_like
-            )));
-            if_node.push(heuristic_node);
+        if let Some(vpn) = &vpn {
+            if_node.push(vpn_heuristic_node(vpn));
         }
 
         root.push(if_node);
@@ -644,5 +1286,165 @@ pub fn print_public_ip_tree(out: &PublicOut, default_iface: Option<Interface>) {
         root.push(Tree::new(tree_label("Default Interface: (not found)")));
     }
 
+    root.set_glyphs(opts.glyphs());
+    println!("{}", root);
+}
+
+pub fn print_check_tree(report: &CheckReport, opts: &TreeOptions) {
+    let host = crate::collector::sys::hostname();
+    let mut root = Tree::new(tree_label(format!("Checks on {}", host)));
+    for f in &report.findings {
+        let level = match f.level {
+            Level::Ok => "OK",
+            Level::Warn => "WARN",
+            Level::Fail => "FAIL",
+        };
+        root.push(Tree::new(format!("[{}] {}: {}", level, f.rule, f.message)));
+    }
+    if report.warnings() == 0 && report.failures() == 0 {
+        root.push(Tree::new(tree_label("[OK] No issues found")));
+    }
+    root.set_glyphs(opts.glyphs());
+    println!("{}", root);
+}
+
+/// Print every field reported by os_info (`os --verbose-os`)
+pub fn print_os_info_tree(info: &os_info::Info, opts: &TreeOptions) {
+    let mut root = Tree::new(tree_label("os_info (raw)"));
+    root.push(Tree::new(format!("Display: {}", info)));
+    root.push(Tree::new(format!("Type: {:?}", info.os_type())));
+    root.push(Tree::new(format!("Version: {}", info.version())));
+    root.push(Tree::new(format!("Edition: {:?}", info.edition())));
+    root.push(Tree::new(format!("Codename: {:?}", info.codename())));
+    root.push(Tree::new(format!("Bitness: {}", info.bitness())));
+    root.push(Tree::new(format!(
+        "Architecture: {:?}",
+        info.architecture()
+    )));
+    root.set_glyphs(opts.glyphs());
+    println!("{}", root);
+}
+
+/// Print per-type traffic totals (`list --sum-by-type`)
+pub fn print_type_totals_tree(groups: &[TypeTotals], unit: Unit, opts: &TreeOptions) {
+    let count: usize = groups.iter().map(|g| g.interfaces).sum();
+    let mut root = Tree::new(tree_label(format!(
+        "Traffic by Type ({} interfaces)",
+        count
+    )));
+    for group in groups {
+        let mut node = Tree::new(tree_label(format!(
+            "{:?} ({} interfaces)",
+            group.if_type, group.interfaces
+        )));
+        node.push(Tree::new(format!(
+            "RX: {}",
+            human_total(group.rx_bytes, unit)
+        )));
+        node.push(Tree::new(format!(
+            "TX: {}",
+            human_total(group.tx_bytes, unit)
+        )));
+        root.push(node);
+    }
+    root.set_glyphs(opts.glyphs());
+    println!("{}", root);
+}
+
+/// Print validation results against an expectations file.
+pub fn print_validate_tree(report: &CheckReport, opts: &TreeOptions) {
+    let host = crate::collector::sys::hostname();
+    let mut root = Tree::new(tree_label(format!("Validation on {}", host)));
+    for f in &report.findings {
+        let level = match f.level {
+            Level::Ok => "PASS",
+            Level::Warn => "WARN",
+            Level::Fail => "FAIL",
+        };
+        root.push(Tree::new(format!("[{}] {}: {}", level, f.rule, f.message)));
+    }
+    root.push(Tree::new(tree_label(format!(
+        "Summary: {} passed, {} failed",
+        report.findings.len() - report.failures(),
+        report.failures()
+    ))));
+    root.set_glyphs(opts.glyphs());
     println!("{}", root);
 }
+
+/// Print the neighbor cache entries.
+pub fn print_neighbor_tree(entries: &[NeighborEntry], opts: &TreeOptions) {
+    let host = crate::collector::sys::hostname();
+    let mut root = Tree::new(tree_label(format!("Neighbors on {}", host)));
+    for n in entries {
+        let mut label = format!("{} -> {} [{}]", n.ip, n.mac, display_name(&n.iface));
+        match (&n.state, n.age_secs) {
+            (Some(state), Some(age)) => label.push_str(&format!(" {} ({}s)", state, age)),
+            (Some(state), None) => label.push_str(&format!(" {}", state)),
+            (None, _) => {}
+        }
+        root.push(Tree::new(label));
+    }
+    if entries.is_empty() {
+        root.push(Tree::new(tree_label("(no entries)")));
+    }
+    root.set_glyphs(opts.glyphs());
+    println!("{}", root);
+}
+
+pub fn print_vpn_tree(report: &VpnReport, opts: &TreeOptions) {
+    let h = &report.heuristic;
+    let mut root = Tree::new(format!(
+        "VPN heuristic for {}",
+        display_name(&report.interface)
+    ));
+    root.push(Tree::new(format!(
+        "VPN-like: {} (score {}, threshold 5)",
+        h.is_vpn_like, h.score
+    )));
+    let mut signals = Tree::new(tree_label("Signals"));
+    for (signal, points) in &h.signals {
+        signals.push(Tree::new(format!("+{} {}", points, signal)));
+    }
+    if h.signals.is_empty() {
+        signals.push(Tree::new(tree_label("(none)")));
+    }
+    root.push(signals);
+    root.set_glyphs(opts.glyphs());
+    println!("{}", root);
+}
+
+/// "Connectivity: OK (3/3 reachable)"
+pub fn connectivity_line(c: &Connectivity) -> String {
+    let up = c.probes.iter().filter(|p| p.reachable).count();
+    format!(
+        "Connectivity: {} ({}/{} reachable)",
+        c.verdict,
+        up,
+        c.probes.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ipv4_scope_label_flags_only_link_local() {
+        let apipa = Some("link-local/APIPA");
+        assert_eq!(ipv4_scope_label(&Ipv4Addr::new(169, 254, 0, 0)), apipa);
+        assert_eq!(ipv4_scope_label(&Ipv4Addr::new(169, 254, 255, 255)), apipa);
+        assert_eq!(ipv4_scope_label(&Ipv4Addr::new(169, 253, 1, 1)), None);
+        assert_eq!(ipv4_scope_label(&Ipv4Addr::new(169, 255, 0, 1)), None);
+        assert_eq!(ipv4_scope_label(&Ipv4Addr::new(192, 168, 1, 10)), None);
+        assert_eq!(ipv4_scope_label(&Ipv4Addr::new(8, 8, 8, 8)), None);
+    }
+
+    #[test]
+    fn ipv4_label_annotates_link_local() {
+        let net = Ipv4Net::new(Ipv4Addr::new(169, 254, 10, 2), 16).unwrap();
+        assert_eq!(ipv4_label(&net), "169.254.10.2/16 (link-local/APIPA)");
+        let net = Ipv4Net::new(Ipv4Addr::new(192, 168, 1, 10), 24).unwrap();
+        assert_eq!(ipv4_label(&net), "192.168.1.10/24");
+    }
+}
@@ -1,16 +1,20 @@
-use crate::{collector::sys::SysInfo, model::snapshot::Snapshot};
+use crate::{
+    db::oui::is_oui_db_initialized, model::iface::InterfaceView, model::snapshot::OsReport,
+};
 use netdev::Interface;
 
 pub fn print_interface_yaml(ifaces: &[Interface]) {
-    let yaml = serde_yaml::to_string(ifaces).unwrap();
+    // With --with-vendor, each interface carries its resolved OUI vendor
+    let yaml = if is_oui_db_initialized() {
+        let views: Vec<InterfaceView> = ifaces.iter().map(InterfaceView::new).collect();
+        serde_yaml::to_string(&views).unwrap()
+    } else {
+        serde_yaml::to_string(ifaces).unwrap()
+    };
     println!("{}", yaml);
 }
 
-pub fn print_snapshot_yaml(sys: &SysInfo, default_iface: Option<Interface>) {
-    let snapshot = Snapshot {
-        sys: sys.clone(),
-        interfaces: default_iface.into_iter().collect(),
-    };
-    let yaml = serde_yaml::to_string(&snapshot).unwrap();
+pub fn print_os_report_yaml(report: &OsReport) {
+    let yaml = serde_yaml::to_string(report).unwrap();
     println!("{}", yaml);
 }